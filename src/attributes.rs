@@ -1,10 +1,12 @@
-use std::fmt::Write;
+//! [Attributes](https://doc.rust-lang.org/stable/reference/attributes.html).
+
+use std::fmt::{self, Write};
 
 use either::Either;
 
 use crate::{
 	expressions::Expression,
-	io::{Input, Parse},
+	io::{first::First, Input, Parse},
 	macros::DelimTokenTree,
 	names::paths::SimplePath,
 	tokens::{
@@ -13,6 +15,9 @@ use crate::{
 	},
 };
 
+/// `#![attr]`, which applies to the enclosing item.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct InnerAttribute<'a> {
 	pub pound: Pound,
 	pub not: Not,
@@ -28,11 +33,18 @@ impl<'a> Parse<'a> for InnerAttribute<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		w.write_str("`#![ … ]`")
 	}
+
+	fn first() -> First {
+		Pound::first()
+	}
 }
 
+/// `#[attr]`, which applies to what follows it.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct OuterAttribute<'a> {
 	pub pound: Pound,
 	pub brackets: Brackets<'a, Attr<'a>>,
@@ -46,11 +58,18 @@ impl<'a> Parse<'a> for OuterAttribute<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		w.write_str("`#[ … ]`")
 	}
+
+	fn first() -> First {
+		Pound::first()
+	}
 }
 
+/// An attribute's path and optional input, like `derive(Debug)` or `doc = "…"`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct Attr<'a> {
 	pub simple_path: SimplePath,
 	pub attr_input: Option<AttrInput<'a>>,
@@ -64,11 +83,14 @@ impl<'a> Parse<'a> for Attr<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		<(SimplePath, Option<AttrInput>)>::describe(w)
 	}
 }
 
+/// A delimited token tree or `= expression` after an attribute's path.
+#[derive(Debug, Clone)]
+#[allow(missing_docs, clippy::large_enum_variant)]
 pub enum AttrInput<'a> {
 	DelimTokenTree(DelimTokenTree<'a>),
 	EqExpression { eq: Eq, expression: Expression<'a> },
@@ -85,7 +107,7 @@ impl<'a> Parse<'a> for AttrInput<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		Either::<DelimTokenTree, (Eq, Expression)>::describe(w)
 	}
 }
@@ -1,66 +1,149 @@
-use std::fmt::Write;
+//! [Expressions](https://doc.rust-lang.org/stable/reference/expressions.html).
+
+use std::fmt::{self, Write};
+
+use either::Either;
+use proc_macro2::Delimiter;
 
 use crate::{
 	attributes::OuterAttribute,
-	io::{Input, Parse},
+	help::catalog::Message,
+	io::{first::First, memo::Memoization, random_access::TokenTree, Input, Parse},
+	macros::MacroInvocation,
+	tokens::{
+		keywords::Let,
+		punctuation::{Comma, FatArrow, Semi},
+	},
 };
 
 use self::{
+	array_expressions::{ArrayExpression, IndexExpression},
+	await_expressions::AwaitExpression,
 	block_expressions::{AsyncBlockExpression, BlockExpression, UnsafeBlockExpression},
+	call_expressions::CallExpression,
+	closure_expressions::ClosureExpression,
+	field_expressions::FieldExpression,
 	grouped_expressions::GroupedExpression,
+	if_expressions::{IfExpression, IfLetExpression},
 	limitations::{ExpressionLimitation, NONE},
 	literal_expressions::LiteralExpression,
-	loop_expressions::{BreakExpression, ContinueExpression},
+	loop_expressions::{BreakExpression, ContinueExpression, LoopExpression},
+	match_expressions::MatchExpression,
+	method_call_expressions::MethodCallExpression,
 	operator_expressions::OperatorExpression,
 	path_expressions::PathExpression,
+	range_expressions::RangeExpression,
+	return_expressions::ReturnExpression,
+	struct_expressions::StructExpression,
+	tuple_expressions::{TupleExpression, TupleIndexingExpression},
+	underscore_expressions::UnderscoreExpression,
 };
 
+pub mod array_expressions;
+pub mod await_expressions;
 pub mod block_expressions;
+pub mod call_expressions;
+pub mod closure_expressions;
+pub mod field_expressions;
 pub mod grouped_expressions;
+pub mod if_expressions;
 pub mod literal_expressions;
 pub mod loop_expressions;
 pub mod match_expressions;
+pub mod method_call_expressions;
 pub mod operator_expressions;
 pub mod path_expressions;
+pub mod range_expressions;
+pub mod return_expressions;
+pub mod struct_expressions;
+pub mod tuple_expressions;
+pub mod underscore_expressions;
 
+/// An expression with or without a block, except for what `LIMITATION` excludes.
+#[derive(Debug, Clone)]
+#[allow(missing_docs, clippy::large_enum_variant)]
 pub enum Expression<'a, LIMITATION: ExpressionLimitation = NONE> {
 	ExpressionWithoutBlock(ExpressionWithoutBlock<'a, LIMITATION>),
-	ExpressionWithBlock(ExpressionWithBlock<'a, LIMITATION>),
+	ExpressionWithBlock(ExpressionWithBlock<'a>),
 }
 
 impl<'a, LIMITATION: ExpressionLimitation> Parse<'a> for Expression<'a, LIMITATION> {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut Input<'a>) -> Self {
-		if let (ewb, Ok(())) = input.try_parse() {
+		// Without this, alternatives that continue past a missing first token would recurse forever.
+		if input.is_end() {
+			input.error_expected()
+		} else if let Some(len) = operator_expressions::invisible_group_len::<LIMITATION>(input) {
+			// Peeled only as a whole, so that operators within it don't bind to what follows.
+			let expression = input.parse_exhaustively(&input.cursor[..len]);
+			input.advance(len);
+			expression
+		} else if let (ewb, Ok(())) = input.try_parse() {
 			Self::ExpressionWithoutBlock(ewb)
 		} else if let (ewb, Ok(())) = input.try_parse() {
 			Self::ExpressionWithBlock(ewb)
 		} else {
-			todo!()
+			input.error_expected()
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-expression").write(w)
+	}
+}
+
+impl<LIMITATION: ExpressionLimitation> Default for Expression<'_, LIMITATION> {
+	fn default() -> Self {
+		Self::ExpressionWithoutBlock(ExpressionWithoutBlock::default())
+	}
+}
+
+/// Terminates the left-recursive postfix expressions.
+///
+/// Where `LIMITATION` excludes struct expressions, a `{` opens the block after a condition or scrutinee instead.
+/// `let` can't continue an expression either, so it starts the next statement after a missing `;`.
+pub(crate) fn is_postfix_end<LIMITATION: ExpressionLimitation>(input: &Input<'_>) -> bool {
+	input.is_end()
+		|| input.peek::<FatArrow>()
+		|| input.peek::<Comma>()
+		|| input.peek::<Semi>()
+		|| input.peek::<Let>()
+		|| (LIMITATION::EXCEPT_STRUCT_EXPRESSION
+			&& matches!(input.next_token(), Some(TokenTree::Group(group)) if group.delimiter == Delimiter::Brace))
 }
 
 pub mod limitations {
+	//! Restrictions on which expressions can appear where, like "except struct expression".
 	#![allow(non_camel_case_types)]
 
+	use std::fmt::Debug;
+
 	use self::sealed::Sealed;
 
 	mod sealed {
 		pub trait Sealed {}
 	}
 
-	pub trait ExpressionLimitation: Sealed {
-		type EXCEPT_STRUCT_EXPRESSION;
+	/// Which expressions an [`Expression`](`super::Expression`) excludes at its top level.
+	pub trait ExpressionLimitation: Sealed + Clone + Debug + 'static {
+		/// This limitation, additionally excluding struct expressions.
+		type EXCEPT_STRUCT_EXPRESSION: ExpressionLimitation;
+		/// Whether struct expressions are excluded, so that a `{` opens the following block instead.
 		const EXCEPT_STRUCT_EXPRESSION: bool = false;
+		/// Whether `&&` and `||` are excluded, so that they can't be confused with `let` chains.
 		const EXCEPT_LAZY_BOOLEAN_OPERATOR_EXPRESSION: bool = false;
 	}
 
+	/// Excludes nothing.
+	#[derive(Debug, Clone, Copy)]
 	pub enum NONE {}
 	impl Sealed for NONE {}
 	impl ExpressionLimitation for NONE {
 		type EXCEPT_STRUCT_EXPRESSION = EXCEPT_STRUCT_EXPRESSION;
 	}
 
+	/// Excludes struct expressions, as in conditions and scrutinees.
+	#[derive(Debug, Clone, Copy)]
 	pub enum EXCEPT_STRUCT_EXPRESSION {}
 	impl Sealed for EXCEPT_STRUCT_EXPRESSION {}
 	impl ExpressionLimitation for EXCEPT_STRUCT_EXPRESSION {
@@ -69,6 +152,8 @@ pub mod limitations {
 		const EXCEPT_STRUCT_EXPRESSION: bool = true;
 	}
 
+	/// Excludes `&&` and `||`, as in `if let` and `while let` scrutinees.
+	#[derive(Debug, Clone, Copy)]
 	pub enum EXCEPT_LAZY_BOOLEAN_OPERATOR_EXPRESSION {}
 	impl Sealed for EXCEPT_LAZY_BOOLEAN_OPERATOR_EXPRESSION {}
 	impl ExpressionLimitation for EXCEPT_LAZY_BOOLEAN_OPERATOR_EXPRESSION {
@@ -78,6 +163,8 @@ pub mod limitations {
 		const EXCEPT_LAZY_BOOLEAN_OPERATOR_EXPRESSION: bool = true;
 	}
 
+	/// Excludes both struct expressions and `&&` and `||`.
+	#[derive(Debug, Clone, Copy)]
 	pub enum EXCEPT_LAZY_BOOLEAN_OPERATOR_EXPRESSION_OR_STRUCT_EXPRESSION {}
 	impl Sealed for EXCEPT_LAZY_BOOLEAN_OPERATOR_EXPRESSION_OR_STRUCT_EXPRESSION {}
 	impl ExpressionLimitation for EXCEPT_LAZY_BOOLEAN_OPERATOR_EXPRESSION_OR_STRUCT_EXPRESSION {
@@ -88,6 +175,9 @@ pub mod limitations {
 	}
 }
 
+/// An expression that doesn't end with a block, with its outer attributes.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub struct ExpressionWithoutBlock<'a, LIMITATION: ExpressionLimitation = NONE> {
 	pub outer_attributes: Vec<OuterAttribute<'a>>,
 	pub variant: ExpressionWithoutBlockContent<'a, LIMITATION>,
@@ -100,9 +190,27 @@ impl<'a, LIMITATION: ExpressionLimitation> Parse<'a> for ExpressionWithoutBlock<
 			variant: input.parse(),
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(
+			Vec<OuterAttribute>,
+			ExpressionWithoutBlockContent<LIMITATION>,
+		)>::describe(w)
+	}
+}
+
+impl<LIMITATION: ExpressionLimitation> Default for ExpressionWithoutBlock<'_, LIMITATION> {
+	fn default() -> Self {
+		Self {
+			outer_attributes: Vec::new(),
+			variant: ExpressionWithoutBlockContent::default(),
+		}
+	}
 }
 
+/// The kinds of [`ExpressionWithoutBlock`].
 #[derive(Debug, Clone)]
+#[allow(missing_docs, clippy::large_enum_variant)]
 pub enum ExpressionWithoutBlockContent<'a, LIMITATION: ExpressionLimitation = NONE> {
 	LiteralExpression(LiteralExpression),
 	PathExpression(PathExpression<'a>),
@@ -121,71 +229,109 @@ pub enum ExpressionWithoutBlockContent<'a, LIMITATION: ExpressionLimitation = NO
 	AsyncBlockExpression(AsyncBlockExpression<'a>),
 	ContinueExpression(ContinueExpression),
 	BreakExpression(BreakExpression<'a>),
-	RangeExpression(RangeExpression<'a>),
+	RangeExpression(RangeExpression<'a, LIMITATION>),
 	ReturnExpression(ReturnExpression<'a>),
-	UnderscoreExpression(UnderscoreExpression<'a>),
+	UnderscoreExpression(UnderscoreExpression),
 	MacroInvocation(MacroInvocation<'a>),
 }
 
 impl<'a, LIMITATION: ExpressionLimitation> Parse<'a>
 	for ExpressionWithoutBlockContent<'a, LIMITATION>
 {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut Input<'a>) -> Self {
-		//TODO: snake_case
-		if let (LiteralExpression, Ok(())) = input.try_parse() {
-			Self::LiteralExpression(LiteralExpression)
-		} else if let (PathExpression, Ok(())) = input.try_parse() {
-			Self::PathExpression(PathExpression)
-		} else if let (OperatorExpression, Ok(())) = input.try_parse() {
-			Self::OperatorExpression(OperatorExpression)
-		} else if let (GroupedExpression, Ok(())) = input.try_parse() {
-			Self::GroupedExpression(GroupedExpression)
-		} else if let (ArrayExpression, Ok(())) = input.try_parse() {
-			Self::ArrayExpression(ArrayExpression)
-		} else if let (AwaitExpression, Ok(())) = input.try_parse() {
-			Self::AwaitExpression(AwaitExpression)
-		} else if let (IndexExpression, Ok(())) = input.try_parse() {
-			Self::IndexExpression(IndexExpression)
-		} else if let (TupleExpression, Ok(())) = input.try_parse() {
-			Self::TupleExpression(TupleExpression)
-		} else if let (TupleIndexingExpression, Ok(())) = input.try_parse() {
-			Self::TupleIndexingExpression(TupleIndexingExpression)
-		} else if let Some((StructExpression, Ok(()))) =
-			(!LIMITATION::EXCEPT_STRUCT_EXPRESSION).then_some(|| input.try_parse())
+		// Operator and range expressions come first, as they split at infix operators (which bind loosest).
+		// Left-recursive postfix expressions follow, since the remaining variants would otherwise match only their left side.
+		// The remaining variants are only attempted if their FIRST set admits the next token, like in `TypeNoBounds`.
+		if let Some(operation) = operator_expressions::parse_operators(input) {
+			match operation {
+				Either::Left(operator_expression) => Self::OperatorExpression(operator_expression),
+				Either::Right(range_expression) => Self::RangeExpression(range_expression),
+			}
+		} else if let Some(postfix_expression) = Self::parse_postfix(input) {
+			postfix_expression
+		} else if let Some(literal_expression) = input.parse() {
+			Self::LiteralExpression(literal_expression)
+		} else if let Some(path_expression) = input.parse() {
+			Self::PathExpression(path_expression)
+		} else if let Some(grouped_expression) = input.parse() {
+			Self::GroupedExpression(grouped_expression)
+		} else if let Some(array_expression) = input.parse() {
+			Self::ArrayExpression(array_expression)
+		} else if let Some(tuple_expression) = input.parse() {
+			Self::TupleExpression(tuple_expression)
+		} else if let Some(struct_expression) = (!LIMITATION::EXCEPT_STRUCT_EXPRESSION)
+			.then(|| input.parse())
+			.flatten()
 		{
-			Self::StructExpression(StructExpression)
-		} else if let (CallExpression, Ok(())) = input.try_parse() {
-			Self::CallExpression(CallExpression)
-		} else if let (MethodCallExpression, Ok(())) = input.try_parse() {
-			Self::MethodCallExpression(MethodCallExpression)
-		} else if let (FieldExpression, Ok(())) = input.try_parse() {
-			Self::FieldExpression(FieldExpression)
-		} else if let (ClosureExpression, Ok(())) = input.try_parse() {
-			Self::ClosureExpression(ClosureExpression)
-		} else if let (AsyncBlockExpression, Ok(())) = input.try_parse() {
-			Self::AsyncBlockExpression(AsyncBlockExpression)
-		} else if let (ContinueExpression, Ok(())) = input.try_parse() {
-			Self::ContinueExpression(ContinueExpression)
-		} else if let (BreakExpression, Ok(())) = input.try_parse() {
-			Self::BreakExpression(BreakExpression)
-		} else if let (RangeExpression, Ok(())) = input.try_parse() {
-			Self::RangeExpression(RangeExpression)
-		} else if let (ReturnExpression, Ok(())) = input.try_parse() {
-			Self::ReturnExpression(ReturnExpression)
-		} else if let (UnderscoreExpression, Ok(())) = input.try_parse() {
-			Self::UnderscoreExpression(UnderscoreExpression)
-		} else if let (MacroInvocation, Ok(())) = input.try_parse() {
-			Self::MacroInvocation(MacroInvocation)
+			Self::StructExpression(struct_expression)
+		} else if let Some(closure_expression) = input.parse() {
+			Self::ClosureExpression(closure_expression)
+		} else if let Some(async_block_expression) = input.parse() {
+			Self::AsyncBlockExpression(async_block_expression)
+		} else if let Some(continue_expression) = input.parse() {
+			Self::ContinueExpression(continue_expression)
+		} else if let Some(break_expression) = input.parse() {
+			Self::BreakExpression(break_expression)
+		} else if let Some(return_expression) = input.parse() {
+			Self::ReturnExpression(return_expression)
+		} else if let Some(underscore_expression) = input.parse() {
+			Self::UnderscoreExpression(underscore_expression)
+		} else if let Some(macro_invocation) = input.parse() {
+			Self::MacroInvocation(macro_invocation)
 		} else {
 			input.error_expected()
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
-		w.write_str("expression")
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-expression").write(w)
+	}
+
+	fn memoization() -> Option<Memoization<Self>> {
+		// SAFETY: The marker is `Self` with all lifetimes `'static`, and dropping `Self` doesn't use what it borrows.
+		Some(unsafe { Memoization::new::<ExpressionWithoutBlockContent<'static, LIMITATION>>() })
+	}
+}
+
+impl<'a, LIMITATION: ExpressionLimitation> ExpressionWithoutBlockContent<'a, LIMITATION> {
+	/// Parses a left-recursive postfix expression from only the tokens before [`is_postfix_end`] with `LIMITATION`,
+	/// since the postfix expressions don't know whether a `{` ends them.
+	#[allow(clippy::same_functions_in_if_condition)]
+	fn parse_postfix(input: &mut Input<'a>) -> Option<Self> {
+		let end = input.find_end(is_postfix_end::<LIMITATION>);
+		let mut bounded = input.with_cursor(&input.cursor[..end]);
+		let postfix_expression = if let (method_call_expression, Ok(())) = bounded.try_parse() {
+			Self::MethodCallExpression(method_call_expression)
+		} else if let (call_expression, Ok(())) = bounded.try_parse() {
+			Self::CallExpression(call_expression)
+		} else if let (await_expression, Ok(())) = bounded.try_parse() {
+			Self::AwaitExpression(await_expression)
+		} else if let (field_expression, Ok(())) = bounded.try_parse() {
+			Self::FieldExpression(field_expression)
+		} else if let (tuple_indexing_expression, Ok(())) = bounded.try_parse() {
+			Self::TupleIndexingExpression(tuple_indexing_expression)
+		} else if let (index_expression, Ok(())) = bounded.try_parse() {
+			Self::IndexExpression(index_expression)
+		} else {
+			return None;
+		};
+		input.advance(end - bounded.cursor.len());
+		Some(postfix_expression)
 	}
 }
 
+impl<LIMITATION: ExpressionLimitation> Default for ExpressionWithoutBlockContent<'_, LIMITATION> {
+	fn default() -> Self {
+		Self::PathExpression(PathExpression::default())
+	}
+}
+
+/// An expression that ends with a block, with its outer attributes.
+///
+/// These can be statements without a trailing `;`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct ExpressionWithBlock<'a> {
 	pub outer_attributes: Vec<OuterAttribute<'a>>,
 	pub variant: ExpressionWithBlockContent<'a>,
@@ -198,8 +344,15 @@ impl<'a> Parse<'a> for ExpressionWithBlock<'a> {
 			variant: input.parse(),
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Vec<OuterAttribute>, ExpressionWithBlockContent)>::describe(w)
+	}
 }
 
+/// The kinds of [`ExpressionWithBlock`].
+#[derive(Debug, Clone)]
+#[allow(missing_docs, clippy::large_enum_variant)]
 pub enum ExpressionWithBlockContent<'a> {
 	BlockExpression(BlockExpression<'a>),
 	UnsafeBlockExpression(UnsafeBlockExpression<'a>),
@@ -210,22 +363,114 @@ pub enum ExpressionWithBlockContent<'a> {
 }
 
 impl<'a> Parse<'a> for ExpressionWithBlockContent<'a> {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut Input<'a>) -> Self {
-		//TODO: snake_case
-		if let (BlockExpression, Ok(())) = input.try_parse() {
-			Self::BlockExpression(BlockExpression)
-		} else if let (UnsafeBlockExpression, Ok(())) = input.try_parse() {
-			Self::UnsafeBlockExpression(UnsafeBlockExpression)
-		} else if let (LoopExpression, Ok(())) = input.try_parse() {
-			Self::LoopExpression(LoopExpression)
-		} else if let (IfExpression, Ok(())) = input.try_parse() {
-			Self::IfExpression(IfExpression)
-		} else if let (IfLetExpression, Ok(())) = input.try_parse() {
-			Self::IfLetExpression(IfLetExpression)
-		} else if let (MatchExpression, Ok(())) = input.try_parse() {
-			Self::MatchExpression(MatchExpression)
+		// Each alternative is only attempted if its FIRST set admits the next token.
+		// `if let` is tried before `if`, since both start with `if`.
+		if let Some(block_expression) = input.parse() {
+			Self::BlockExpression(block_expression)
+		} else if let Some(unsafe_block_expression) = input.parse() {
+			Self::UnsafeBlockExpression(unsafe_block_expression)
+		} else if let Some(loop_expression) = input.parse() {
+			Self::LoopExpression(loop_expression)
+		} else if let Some(if_let_expression) = input.parse() {
+			Self::IfLetExpression(if_let_expression)
+		} else if let Some(if_expression) = input.parse() {
+			Self::IfExpression(if_expression)
+		} else if let Some(match_expression) = input.parse() {
+			Self::MatchExpression(match_expression)
 		} else {
-			todo!()
+			input.error_expected()
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-expression").write(w)
+	}
+
+	fn first() -> First {
+		BlockExpression::first()
+			.or(UnsafeBlockExpression::first())
+			.or(LoopExpression::first())
+			.or(IfExpression::first())
+			.or(IfLetExpression::first())
+			.or(MatchExpression::first())
+	}
+
+	fn memoization() -> Option<Memoization<Self>> {
+		// SAFETY: The marker is `Self` with all lifetimes `'static`, and dropping `Self` doesn't use what it borrows.
+		Some(unsafe { Memoization::new::<ExpressionWithBlockContent<'static>>() })
+	}
+}
+
+impl Default for ExpressionWithBlockContent<'_> {
+	fn default() -> Self {
+		Self::BlockExpression(BlockExpression::default())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::parse_str;
+
+	use super::{Expression, ExpressionWithoutBlock, ExpressionWithoutBlockContent};
+
+	/// What kind of expression `source` parses as.
+	fn kind(source: &str) -> &'static str {
+		parse_str(source, |parsing| {
+			let (expression, diagnostics): (Expression, _) = parsing.parse();
+			assert!(diagnostics.is_empty(), "`{source}`: {diagnostics:?}");
+			let Expression::ExpressionWithoutBlock(ExpressionWithoutBlock { variant, .. }) =
+				expression
+			else {
+				panic!("`{source}`: {expression:?}");
+			};
+			match variant {
+				ExpressionWithoutBlockContent::PathExpression(_) => "path",
+				ExpressionWithoutBlockContent::CallExpression(_) => "call",
+				ExpressionWithoutBlockContent::MethodCallExpression(_) => "method call",
+				other => panic!("`{source}`: {other:?}"),
+			}
+		})
+		.unwrap()
+	}
+
+	/// The indices of the tuple indexing expressions nested in `source`, innermost first.
+	fn tuple_indices(source: &str) -> Vec<String> {
+		parse_str(source, |parsing| {
+			let (expression, diagnostics): (Expression, _) = parsing.parse();
+			assert!(diagnostics.is_empty(), "`{source}`: {diagnostics:?}");
+			let mut indices = Vec::new();
+			let mut expression = &expression;
+			while let Expression::ExpressionWithoutBlock(ExpressionWithoutBlock {
+				variant: ExpressionWithoutBlockContent::TupleIndexingExpression(tuple_indexing),
+				..
+			}) = expression
+			{
+				indices.insert(0, tuple_indexing.tuple_index.literal.to_string());
+				expression = &tuple_indexing.expression;
+			}
+			indices
+		})
+		.unwrap()
+	}
+
+	#[test]
+	fn float_literals_are_split_into_tuple_indices() {
+		assert_eq!(tuple_indices("a.0"), ["0"]);
+		assert_eq!(tuple_indices("a.0.1"), ["0", "1"]);
+		assert_eq!(tuple_indices("self.0.0"), ["0", "0"]);
+		assert_eq!(tuple_indices("a.1.2.3"), ["1", "2", "3"]);
+		assert_eq!(tuple_indices("a.0 .1"), ["0", "1"]);
+		assert_eq!(tuple_indices("0..1.5"), Vec::<String>::new());
+	}
+
+	#[test]
+	fn turbofish_commas_are_generic_arguments() {
+		assert_eq!(kind("f::<A, B>(x)"), "call");
+		assert_eq!(kind("HashMap::<K, V>::new()"), "call");
+		assert_eq!(kind("HashMap::<K, V>::new"), "path");
+		assert_eq!(kind("x.collect::<HashMap<K, V>>()"), "method call");
+		assert_eq!(kind("x.f::<fn() -> A, B>()"), "method call");
+	}
 }
@@ -0,0 +1,117 @@
+//! [Array and array index expressions](https://doc.rust-lang.org/stable/reference/expressions/array-expr.html).
+
+use std::fmt::{self, Write};
+
+use crate::{
+	help::{catalog::Message, Expected},
+	io::{arena::Node, first::First, Input, Parse},
+	tokens::{
+		delimiters::Brackets,
+		punctuation::{Comma, Semi},
+	},
+};
+
+use super::{is_postfix_end, limitations::NONE, Expression};
+
+/// `[a, b, c]`, `[value; length]` or `[]`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct ArrayExpression<'a> {
+	pub brackets: Brackets<'a, Option<ArrayElements<'a>>>,
+}
+
+impl<'a> Parse<'a> for ArrayExpression<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			brackets: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Brackets::<Option<ArrayElements>>::describe(w)
+	}
+
+	fn first() -> First {
+		Brackets::<Option<ArrayElements>>::first()
+	}
+}
+
+/// The contents of a non-empty [`ArrayExpression`].
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub enum ArrayElements<'a> {
+	/// `[a, b, c]`
+	List {
+		expression: Node<'a, Expression<'a>>,
+		rest: Vec<(Comma, Node<'a, Expression<'a>>)>,
+		comma: Option<Comma>,
+	},
+	/// `[value; length]`
+	Repeat {
+		expression: Node<'a, Expression<'a>>,
+		semi: Semi,
+		length: Node<'a, Expression<'a>>,
+	},
+}
+
+impl<'a> Parse<'a> for ArrayElements<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		if let ((expression, semi, length), Ok(())) = input.try_parse() {
+			Self::Repeat {
+				expression,
+				semi,
+				length,
+			}
+		} else {
+			Self::List {
+				expression: input.parse(),
+				rest: input.parse(),
+				comma: input.parse(),
+			}
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		w.write_char('(')?;
+		<(Expression, Vec<(Comma, Expression)>, Option<Comma>)>::describe(w)?;
+		w.write_char('|')?;
+		<(Expression, Semi, Expression)>::describe(w)?;
+		w.write_char(')')
+	}
+}
+
+impl Default for ArrayElements<'_> {
+	fn default() -> Self {
+		Self::List {
+			expression: Node::default(),
+			rest: Vec::new(),
+			comma: None,
+		}
+	}
+}
+
+/// `expression[index]`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct IndexExpression<'a> {
+	pub expression: Node<'a, Expression<'a>>,
+	pub brackets: Brackets<'a, Node<'a, Expression<'a>>>,
+}
+
+impl<'a> Parse<'a> for IndexExpression<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		let (expression, brackets) =
+			input.right_aligned_left_recursive_parse(is_postfix_end::<NONE>);
+		Self {
+			expression,
+			brackets,
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-before")
+			.arg("item", Expected::of::<(Expression, Brackets<Expression>)>())
+			.arg("terminators", "(end|`=>`|`,`|`;`)")
+			.write(w)
+	}
+}
@@ -0,0 +1,39 @@
+//! [Await expressions](https://doc.rust-lang.org/stable/reference/expressions/await-expr.html).
+
+use std::fmt::{self, Write};
+
+use crate::{
+	help::{catalog::Message, Expected},
+	io::{arena::Node, Input, Parse},
+	tokens::{keywords::Await, punctuation::Dot},
+};
+
+use super::{is_postfix_end, limitations::NONE, Expression};
+
+/// `expression.await`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct AwaitExpression<'a> {
+	pub expression: Node<'a, Expression<'a>>,
+	pub dot: Dot,
+	pub r#await: Await,
+}
+
+impl<'a> Parse<'a> for AwaitExpression<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		let (expression, (dot, r#await)) =
+			input.right_aligned_left_recursive_parse(is_postfix_end::<NONE>);
+		Self {
+			expression,
+			dot,
+			r#await,
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-before")
+			.arg("item", Expected::of::<(Expression, Dot, Await)>())
+			.arg("terminators", "(end|`=>`|`,`|`;`)")
+			.write(w)
+	}
+}
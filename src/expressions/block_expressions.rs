@@ -1,16 +1,27 @@
+//! [Block expressions](https://doc.rust-lang.org/stable/reference/expressions/block-expr.html).
+
+use std::fmt::{self, Write};
+
 use vec1::Vec1;
 
 use crate::{
-	help::DiagnosticsList,
-	io::{Input, Parse},
+	attributes::InnerAttribute,
+	io::{arena::Node, first::First, Input, Parse},
+	statements::Statement,
 	tokens::{
+		delimiters::Braces,
 		keywords::{Async, Move, Unsafe},
-		Braces,
+		punctuation::Semi,
 	},
 };
 
+use super::ExpressionWithoutBlock;
+
+/// `{ statements }`, optionally ending with an expression that is its value.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct BlockExpression<'a> {
-	pub braces: Braces<'a, (Vec<InnerAttribute<'a>>, Statements<'a>)>,
+	pub braces: Braces<'a, (Vec<InnerAttribute<'a>>, Option<Statements<'a>>)>,
 }
 
 impl<'a> Parse<'a> for BlockExpression<'a> {
@@ -19,21 +30,96 @@ impl<'a> Parse<'a> for BlockExpression<'a> {
 			braces: input.parse(),
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Braces::<(Vec<InnerAttribute>, Option<Statements>)>::describe(w)
+	}
+
+	fn first() -> First {
+		Braces::<(Vec<InnerAttribute>, Option<Statements>)>::first()
+	}
 }
 
+/// The contents of a [`BlockExpression`] after its inner attributes, if any.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub enum Statements<'a> {
 	Statements {
 		statements: Vec1<Statement<'a>>,
 	},
 	StatementsExpressionWithoutBlock {
 		statements: Vec1<Statement<'a>>,
-		expression_without_block: ExpressionWithoutBlock<'a>,
+		expression_without_block: Node<'a, ExpressionWithoutBlock<'a>>,
 	},
 	ExpressionWithoutBlock {
-		expression_without_block: ExpressionWithoutBlock<'a>,
+		expression_without_block: Node<'a, ExpressionWithoutBlock<'a>>,
 	},
 }
 
+impl<'a> Parse<'a> for Statements<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		let mut statements = Vec::new();
+		let expression_without_block = loop {
+			if input.is_end() {
+				break None;
+			}
+
+			// A trailing expression is only one if nothing follows it.
+			let mut tail = input.clone();
+			let failures = input.diagnostics.failures_checkpoint();
+			if let (expression_without_block, Ok(())) = tail.try_parse() {
+				if tail.is_end() {
+					*input = tail;
+					break Some(expression_without_block);
+				}
+			}
+			// Otherwise, the statement parsed instead reports what's wrong here.
+			input.diagnostics.discard_failures(failures);
+
+			let checkpoint = input.diagnostics.checkpoint();
+			let before = input.cursor.len();
+			statements.push(input.parse());
+			// Errors were reported if nothing was consumed, so this skips to the next statement like rustc.
+			if input.cursor.len() == before {
+				let end = input.find_end(Input::peek::<Semi>);
+				input.advance(end);
+				input.consume(1);
+			}
+			// Errors in later statements don't follow on from this one's.
+			input.diagnostics.end_regions(checkpoint, input.cursor);
+		};
+
+		match (Vec1::try_from_vec(statements), expression_without_block) {
+			(Ok(statements), None) => Self::Statements { statements },
+			(Ok(statements), Some(expression_without_block)) => {
+				Self::StatementsExpressionWithoutBlock {
+					statements,
+					expression_without_block,
+				}
+			}
+			(Err(_), Some(expression_without_block)) => Self::ExpressionWithoutBlock {
+				expression_without_block,
+			},
+			(Err(_), None) => input.error_expected(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Vec<Statement>, Option<ExpressionWithoutBlock>)>::describe(w)
+	}
+}
+
+impl Default for Statements<'_> {
+	fn default() -> Self {
+		Self::Statements {
+			statements: Vec1::new(Statement::default()),
+		}
+	}
+}
+
+/// `async { … }` or `async move { … }`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct AsyncBlockExpression<'a> {
 	pub r#async: Async,
 	pub r#move: Option<Move>,
@@ -48,8 +134,19 @@ impl<'a> Parse<'a> for AsyncBlockExpression<'a> {
 			block_expression: input.parse(),
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Async, Option<Move>, BlockExpression)>::describe(w)
+	}
+
+	fn first() -> First {
+		Async::first()
+	}
 }
 
+/// `unsafe { … }`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct UnsafeBlockExpression<'a> {
 	pub r#unsafe: Unsafe,
 	pub block_expression: BlockExpression<'a>,
@@ -62,4 +159,12 @@ impl<'a> Parse<'a> for UnsafeBlockExpression<'a> {
 			block_expression: input.parse(),
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Unsafe, BlockExpression)>::describe(w)
+	}
+
+	fn first() -> First {
+		Unsafe::first()
+	}
 }
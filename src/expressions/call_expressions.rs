@@ -0,0 +1,63 @@
+//! [Call expressions](https://doc.rust-lang.org/stable/reference/expressions/call-expr.html).
+
+use std::fmt::{self, Write};
+
+use crate::{
+	help::{catalog::Message, Expected},
+	io::{arena::Node, Input, Parse},
+	tokens::{delimiters::Parentheses, punctuation::Comma},
+};
+
+use super::{is_postfix_end, limitations::NONE, Expression};
+
+/// `function(arguments)`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct CallExpression<'a> {
+	pub expression: Node<'a, Expression<'a>>,
+	pub parentheses: Parentheses<'a, Option<CallParams<'a>>>,
+}
+
+impl<'a> Parse<'a> for CallExpression<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		let (expression, parentheses) =
+			input.right_aligned_left_recursive_parse(is_postfix_end::<NONE>);
+		Self {
+			expression,
+			parentheses,
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-before")
+			.arg(
+				"item",
+				Expected::of::<(Expression, Parentheses<Option<CallParams>>)>(),
+			)
+			.arg("terminators", "(end|`=>`|`,`|`;`)")
+			.write(w)
+	}
+}
+
+/// Comma-separated call arguments, with an optional trailing comma.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct CallParams<'a> {
+	pub expression: Node<'a, Expression<'a>>,
+	pub rest: Vec<(Comma, Node<'a, Expression<'a>>)>,
+	pub comma: Option<Comma>,
+}
+
+impl<'a> Parse<'a> for CallParams<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			expression: input.parse(),
+			rest: input.parse(),
+			comma: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Expression, Vec<(Comma, Expression)>, Option<Comma>)>::describe(w)
+	}
+}
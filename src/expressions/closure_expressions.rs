@@ -0,0 +1,114 @@
+//! [Closure expressions](https://doc.rust-lang.org/stable/reference/expressions/closure-expr.html).
+
+use std::fmt::{self, Write};
+
+use either::Either;
+
+use crate::{
+	attributes::OuterAttribute,
+	io::{arena::Node, first::First, Input, Parse},
+	patterns::PatternNoTopAlt,
+	tokens::{
+		keywords::Move,
+		punctuation::{Colon, Comma, Or, OrOr, RArrow},
+	},
+	type_system::types::{Type, TypeNoBounds},
+};
+
+use super::{block_expressions::BlockExpression, Expression};
+
+/// `|parameters| body` or `|parameters| -> Type { body }`, optionally `move`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub struct ClosureExpression<'a> {
+	pub r#move: Option<Move>,
+	pub parameters: Either<OrOr, (Or, Option<ClosureParameters<'a>>, Or)>,
+	pub body: Either<(RArrow, TypeNoBounds<'a>, BlockExpression<'a>), Node<'a, Expression<'a>>>,
+}
+
+impl<'a> Parse<'a> for ClosureExpression<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		let r#move = input.parse();
+		// The body is only parsed past parameters, as it would otherwise recurse at the same position.
+		let parameters = if input.peek::<OrOr>() {
+			Either::Left(input.parse())
+		} else if input.admits::<Or>() {
+			Either::Right(input.parse())
+		} else {
+			return input.error_expected();
+		};
+		Self {
+			r#move,
+			parameters,
+			body: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(
+			Option<Move>,
+			Either<OrOr, (Or, Option<ClosureParameters>, Or)>,
+			Either<(RArrow, TypeNoBounds, BlockExpression), Expression>,
+		)>::describe(w)
+	}
+
+	fn first() -> First {
+		Move::first().or(Or::first()).or(OrOr::first())
+	}
+}
+
+impl Default for ClosureExpression<'_> {
+	fn default() -> Self {
+		Self {
+			r#move: None,
+			parameters: Either::Left(OrOr::default()),
+			body: Either::Right(Node::default()),
+		}
+	}
+}
+
+/// The comma-separated parameters between a closure's `|`s.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct ClosureParameters<'a> {
+	pub closure_param: ClosureParam<'a>,
+	pub rest: Vec<(Comma, ClosureParam<'a>)>,
+	pub comma: Option<Comma>,
+}
+
+impl<'a> Parse<'a> for ClosureParameters<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			closure_param: input.parse(),
+			rest: input.parse(),
+			comma: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(ClosureParam, Vec<(Comma, ClosureParam)>, Option<Comma>)>::describe(w)
+	}
+}
+
+/// A closure parameter's pattern, with an optional `: Type`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct ClosureParam<'a> {
+	pub outer_attributes: Vec<OuterAttribute<'a>>,
+	pub pattern_no_top_alt: PatternNoTopAlt<'a>,
+	pub r#type: Option<(Colon, Type<'a>)>,
+}
+
+impl<'a> Parse<'a> for ClosureParam<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			outer_attributes: input.parse(),
+			pattern_no_top_alt: input.parse(),
+			r#type: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Vec<OuterAttribute>, PatternNoTopAlt, Option<(Colon, Type)>)>::describe(w)
+	}
+}
@@ -0,0 +1,40 @@
+//! [Field access expressions](https://doc.rust-lang.org/stable/reference/expressions/field-expr.html).
+
+use std::fmt::{self, Write};
+
+use crate::{
+	help::{catalog::Message, Expected},
+	identifiers::Identifier,
+	io::{arena::Node, Input, Parse},
+	tokens::punctuation::Dot,
+};
+
+use super::{is_postfix_end, limitations::NONE, Expression};
+
+/// `expression.field`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct FieldExpression<'a> {
+	pub expression: Node<'a, Expression<'a>>,
+	pub dot: Dot,
+	pub identifier: Identifier,
+}
+
+impl<'a> Parse<'a> for FieldExpression<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		let (expression, (dot, identifier)) =
+			input.right_aligned_left_recursive_parse(is_postfix_end::<NONE>);
+		Self {
+			expression,
+			dot,
+			identifier,
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-before")
+			.arg("item", Expected::of::<(Expression, Dot, Identifier)>())
+			.arg("terminators", "(end|`=>`|`,`|`;`)")
+			.write(w)
+	}
+}
@@ -1,25 +1,37 @@
-use std::fmt::Write;
+//! [Grouped expressions](https://doc.rust-lang.org/stable/reference/expressions/grouped-expr.html).
+
+use std::fmt::{self, Write};
 
 use crate::{
-	io::{Input, Parse},
+	io::{arena::Node, first::First, Input, Parse},
 	tokens::delimiters::Parentheses,
 };
 
-use super::Expression;
+use super::{tuple_expressions::is_tuple, Expression};
 
+/// `( expression )`.
 #[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct GroupedExpression<'a> {
-	pub parens: Parentheses<'a, Box<Expression<'a>>>,
+	pub parens: Parentheses<'a, Node<'a, Expression<'a>>>,
 }
 
 impl<'a> Parse<'a> for GroupedExpression<'a> {
 	fn parse(input: &mut Input<'a>) -> Self {
+		if is_tuple(input.cursor) {
+			// That's a `TupleExpression`.
+			return input.error_expected();
+		}
 		Self {
 			parens: input.parse(),
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		Parentheses::<Expression>::describe(w)
 	}
+
+	fn first() -> First {
+		Parentheses::<Expression>::first()
+	}
 }
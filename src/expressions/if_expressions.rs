@@ -0,0 +1,139 @@
+//! [`if` and `if let` expressions](https://doc.rust-lang.org/stable/reference/expressions/if-expr.html).
+
+use std::fmt::{self, Write};
+
+use crate::{
+	io::{arena::Node, first::First, Input, Parse},
+	patterns::Pattern,
+	tokens::{
+		keywords::{Else, If, Let},
+		punctuation::Eq,
+	},
+};
+
+use super::{
+	block_expressions::BlockExpression,
+	limitations::{EXCEPT_LAZY_BOOLEAN_OPERATOR_EXPRESSION, EXCEPT_STRUCT_EXPRESSION},
+	match_expressions::Scrutinee,
+	Expression,
+};
+
+/// `if condition { … }`, optionally followed by `else`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct IfExpression<'a> {
+	pub r#if: If,
+	/// Except struct expression!
+	pub expression: Node<'a, Expression<'a, EXCEPT_STRUCT_EXPRESSION>>,
+	pub block_expression: BlockExpression<'a>,
+	pub r#else: Option<(Else, ElseBranch<'a>)>,
+}
+
+impl<'a> Parse<'a> for IfExpression<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			r#if: input.parse(),
+			expression: input.parse(),
+			block_expression: input.parse(),
+			r#else: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(
+			If,
+			Expression<EXCEPT_STRUCT_EXPRESSION>,
+			BlockExpression,
+			Option<(Else, ElseBranch)>,
+		)>::describe(w)
+	}
+
+	fn first() -> First {
+		If::first()
+	}
+}
+
+/// `if let pattern = scrutinee { … }`, optionally followed by `else`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct IfLetExpression<'a> {
+	pub r#if: If,
+	pub r#let: Let,
+	pub pattern: Pattern<'a>,
+	pub eq: Eq,
+	pub scrutinee: Scrutinee<'a, EXCEPT_LAZY_BOOLEAN_OPERATOR_EXPRESSION>,
+	pub block_expression: BlockExpression<'a>,
+	pub r#else: Option<(Else, ElseBranch<'a>)>,
+}
+
+impl<'a> Parse<'a> for IfLetExpression<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			r#if: input.parse(),
+			r#let: input.parse(),
+			pattern: input.parse(),
+			eq: input.parse(),
+			scrutinee: input.parse(),
+			block_expression: input.parse(),
+			r#else: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(
+			If,
+			Let,
+			Pattern,
+			Eq,
+			Scrutinee<EXCEPT_LAZY_BOOLEAN_OPERATOR_EXPRESSION>,
+			BlockExpression,
+		)>::describe(w)
+	}
+
+	fn first() -> First {
+		If::first()
+	}
+}
+
+/// What follows `else`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs, clippy::large_enum_variant)]
+pub enum ElseBranch<'a> {
+	BlockExpression(BlockExpression<'a>),
+	IfExpression(Node<'a, IfExpression<'a>>),
+	IfLetExpression(Node<'a, IfLetExpression<'a>>),
+}
+
+impl<'a> Parse<'a> for ElseBranch<'a> {
+	#[allow(clippy::same_functions_in_if_condition)]
+	fn parse(input: &mut Input<'a>) -> Self {
+		// `if let` is tried before `if`, since `let` isn't an expression.
+		if let Some(block_expression) = input.parse() {
+			Self::BlockExpression(block_expression)
+		} else if let (if_let_expression, Ok(())) = input.try_parse() {
+			Self::IfLetExpression(if_let_expression)
+		} else if let (if_expression, Ok(())) = input.try_parse() {
+			Self::IfExpression(if_expression)
+		} else {
+			input.error_expected()
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		w.write_char('(')?;
+		BlockExpression::describe(w)?;
+		w.write_char('|')?;
+		If::describe(w)?;
+		w.write_str(" …)")
+	}
+
+	fn first() -> First {
+		BlockExpression::first().or(If::first())
+	}
+}
+
+impl Default for ElseBranch<'_> {
+	fn default() -> Self {
+		Self::BlockExpression(BlockExpression::default())
+	}
+}
@@ -1,7 +1,17 @@
+//! [Literal expressions](https://doc.rust-lang.org/stable/reference/expressions/literal-expr.html).
+
+use std::fmt::{self, Write};
+
 use proc_macro2::Literal;
 
-use crate::io::{Input, Parse};
+use crate::{
+	io::{first::First, Input, Parse},
+	patterns::default_literal,
+};
 
+/// A literal, like `1`, `'c'` or `"text"`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub struct LiteralExpression {
 	pub literal: Literal,
 }
@@ -12,4 +22,20 @@ impl Parse<'_> for LiteralExpression {
 			literal: input.parse(),
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Literal::describe(w)
+	}
+
+	fn first() -> First {
+		Literal::first()
+	}
+}
+
+impl Default for LiteralExpression {
+	fn default() -> Self {
+		Self {
+			literal: default_literal(),
+		}
+	}
 }
@@ -1,7 +1,10 @@
-use std::fmt::Write;
+//! [Loops and other breakable expressions](https://doc.rust-lang.org/stable/reference/expressions/loop-expr.html).
+
+use std::fmt::{self, Write};
 
 use crate::{
-	io::{Input, Parse},
+	help::catalog::Message,
+	io::{arena::Node, first::First, Input, Parse},
 	patterns::Pattern,
 	tokens::{
 		keywords::{Break, Continue, For, In, Let, Loop, While},
@@ -17,40 +20,53 @@ use super::{
 	Expression,
 };
 
+/// A loop or labelled block, after an optional `'label:`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct LoopExpression<'a> {
 	pub loop_label: Option<LoopLabel>,
 	pub variant: LoopExpressionVariant<'a>,
 }
 
 impl<'a> Parse<'a> for LoopExpression<'a> {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut Input<'a>) -> Self {
-		let loop_label = input.parse();
+		let loop_label: Option<LoopLabel> = input.parse();
 		let has_label = loop_label.is_some();
 		Self {
 			loop_label,
 			variant: if let (infinite, Ok(())) = input.try_parse() {
-				Self::InfiniteLoopExpression(infinite)
+				LoopExpressionVariant::InfiniteLoopExpression(infinite)
 			} else if let (predicated, Ok(())) = input.try_parse() {
-				Self::PredicateLoopExpression(predicated)
+				LoopExpressionVariant::PredicateLoopExpression(predicated)
 			} else if let (patted, Ok(())) = input.try_parse() {
-				Self::PredicatePatternLoopExpression(patted)
+				LoopExpressionVariant::PredicatePatternLoopExpression(patted)
 			} else if let (iteratored, Ok(())) = input.try_parse() {
-				Self::IteratorLoopExpression(iteratored)
-			} else if let Some((block, Ok(()))) = has_label.then_some(|| input.try_parse()) {
-				Self::LabelBlockExpression(block)
+				LoopExpressionVariant::IteratorLoopExpression(iteratored)
+			} else if let Some((block, Ok(()))) = has_label.then(|| input.try_parse()) {
+				LoopExpressionVariant::LabelBlockExpression(block)
 			} else {
-				input.error_expected()
+				return input.error_expected();
 			},
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
-		w.write_str("LoopExpression")
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-loop-expression").write(w)
+	}
+
+	fn first() -> First {
+		LoopLabel::first()
+			.or(InfiniteLoopExpression::first())
+			.or(PredicateLoopExpression::first())
+			.or(PredicatePatternLoopExpression::first())
+			.or(IteratorLoopExpression::first())
 	}
 }
 
 /// This is just a helper enum for [`LoopExpression`], so it doesn't implement [`Parse`].
 #[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub enum LoopExpressionVariant<'a> {
 	InfiniteLoopExpression(InfiniteLoopExpression<'a>),
 	PredicateLoopExpression(PredicateLoopExpression<'a>),
@@ -65,7 +81,9 @@ impl Default for LoopExpressionVariant<'_> {
 	}
 }
 
+/// `loop { … }`.
 #[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct InfiniteLoopExpression<'a> {
 	pub r#loop: Loop,
 	pub block_expression: BlockExpression<'a>,
@@ -79,16 +97,22 @@ impl<'a> Parse<'a> for InfiniteLoopExpression<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		<(Loop, BlockExpression)>::describe(w)
 	}
+
+	fn first() -> First {
+		Loop::first()
+	}
 }
 
+/// `while condition { … }`.
 #[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct PredicateLoopExpression<'a> {
 	pub r#while: While,
 	/// Except struct expression!
-	pub expression: Box<Expression<'a, EXCEPT_STRUCT_EXPRESSION>>,
+	pub expression: Node<'a, Expression<'a, EXCEPT_STRUCT_EXPRESSION>>,
 	pub block_expression: BlockExpression<'a>,
 }
 
@@ -101,12 +125,18 @@ impl<'a> Parse<'a> for PredicateLoopExpression<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
-		<(While, Expression, BlockExpression<EXCEPT_STRUCT_EXPRESSION>)>::describe(w)
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(While, Expression<EXCEPT_STRUCT_EXPRESSION>, BlockExpression)>::describe(w)
+	}
+
+	fn first() -> First {
+		While::first()
 	}
 }
 
+/// `while let pattern = scrutinee { … }`.
 #[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct PredicatePatternLoopExpression<'a> {
 	pub r#while: While,
 	pub r#let: Let,
@@ -128,7 +158,7 @@ impl<'a> Parse<'a> for PredicatePatternLoopExpression<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		<(
 			While,
 			Let,
@@ -138,14 +168,20 @@ impl<'a> Parse<'a> for PredicatePatternLoopExpression<'a> {
 			BlockExpression,
 		)>::describe(w)
 	}
+
+	fn first() -> First {
+		While::first()
+	}
 }
 
+/// `for pattern in expression { … }`.
 #[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct IteratorLoopExpression<'a> {
 	pub r#for: For,
 	pub pattern: Pattern<'a>,
 	pub r#in: In,
-	pub expression: Box<Expression<'a, EXCEPT_STRUCT_EXPRESSION>>,
+	pub expression: Node<'a, Expression<'a, EXCEPT_STRUCT_EXPRESSION>>,
 	pub block_expression: BlockExpression<'a>,
 }
 
@@ -160,7 +196,7 @@ impl<'a> Parse<'a> for IteratorLoopExpression<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		<(
 			For,
 			Pattern,
@@ -169,12 +205,18 @@ impl<'a> Parse<'a> for IteratorLoopExpression<'a> {
 			BlockExpression,
 		)>::describe(w)
 	}
+
+	fn first() -> First {
+		For::first()
+	}
 }
 
+/// `'label:` before a loop or block.
 #[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct LoopLabel {
-	lifetime_or_label: LifetimeOrLabel,
-	colon: Colon,
+	pub lifetime_or_label: LifetimeOrLabel,
+	pub colon: Colon,
 }
 
 impl Parse<'_> for LoopLabel {
@@ -185,16 +227,22 @@ impl Parse<'_> for LoopLabel {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		<(LifetimeOrLabel, Colon)>::describe(w)
 	}
+
+	fn first() -> First {
+		LifetimeOrLabel::first()
+	}
 }
 
+/// `break`, with an optional label and value.
 #[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct BreakExpression<'a> {
 	pub r#break: Break,
 	pub lifetime_or_label: Option<LifetimeOrLabel>,
-	pub expression: Option<Box<Expression<'a>>>,
+	pub expression: Option<Node<'a, Expression<'a>>>,
 }
 
 impl<'a> Parse<'a> for BreakExpression<'a> {
@@ -206,12 +254,18 @@ impl<'a> Parse<'a> for BreakExpression<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		<(Break, Option<LifetimeOrLabel>, Option<Expression>)>::describe(w)
 	}
+
+	fn first() -> First {
+		Break::first()
+	}
 }
 
+/// A block after a [`LoopLabel`], which `break 'label value` can exit.
 #[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct LabelBlockExpression<'a> {
 	pub block: BlockExpression<'a>,
 }
@@ -223,11 +277,18 @@ impl<'a> Parse<'a> for LabelBlockExpression<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		BlockExpression::describe(w)
 	}
+
+	fn first() -> First {
+		BlockExpression::first()
+	}
 }
 
+/// `continue`, with an optional label.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct ContinueExpression {
 	pub r#continue: Continue,
 	pub lifetime_or_label: Option<LifetimeOrLabel>,
@@ -241,7 +302,11 @@ impl Parse<'_> for ContinueExpression {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
-		<(Continue, LifetimeOrLabel)>::describe(w)
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Continue, Option<LifetimeOrLabel>)>::describe(w)
+	}
+
+	fn first() -> First {
+		Continue::first()
 	}
 }
@@ -1,15 +1,59 @@
-use std::fmt::Write;
+//! [`match` expressions](https://doc.rust-lang.org/stable/reference/expressions/match-expr.html).
 
-use crate::io::{Input, Parse};
+use std::fmt::{self, Write};
+
+use crate::{
+	attributes::{InnerAttribute, OuterAttribute},
+	io::{arena::Node, first::First, Input, Parse},
+	patterns::Pattern,
+	tokens::{
+		delimiters::Braces,
+		keywords::{If, Match},
+		punctuation::{Comma, FatArrow},
+	},
+};
 
 use super::{
 	limitations::{ExpressionLimitation, NONE},
 	Expression,
 };
 
+/// `match scrutinee { arms }`.
 #[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct MatchExpression<'a> {
+	pub r#match: Match,
+	pub scrutinee: Scrutinee<'a>,
+	pub braces: Braces<'a, (Vec<InnerAttribute<'a>>, Option<MatchArms<'a>>)>,
+}
+
+impl<'a> Parse<'a> for MatchExpression<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			r#match: input.parse(),
+			scrutinee: input.parse(),
+			braces: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(
+			Match,
+			Scrutinee,
+			Braces<(Vec<InnerAttribute>, Option<MatchArms>)>,
+		)>::describe(w)
+	}
+
+	fn first() -> First {
+		Match::first()
+	}
+}
+
+/// The expression matched on, which can't be a struct expression.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub struct Scrutinee<'a, LIMITATION: ExpressionLimitation = NONE> {
-	pub expression: Box<Expression<'a, LIMITATION::EXCEPT_STRUCT_EXPRESSION>>,
+	pub expression: Node<'a, Expression<'a, LIMITATION::EXCEPT_STRUCT_EXPRESSION>>,
 }
 
 impl<'a, LIMITATION: ExpressionLimitation> Parse<'a> for Scrutinee<'a, LIMITATION> {
@@ -19,7 +63,102 @@ impl<'a, LIMITATION: ExpressionLimitation> Parse<'a> for Scrutinee<'a, LIMITATIO
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		Expression::<LIMITATION::EXCEPT_STRUCT_EXPRESSION>::describe(w)
 	}
 }
+
+impl<LIMITATION: ExpressionLimitation> Default for Scrutinee<'_, LIMITATION> {
+	fn default() -> Self {
+		Self {
+			expression: Node::default(),
+		}
+	}
+}
+
+/// The arms of a [`MatchExpression`], each but the last of which must end with a comma unless its expression is block-like.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct MatchArms<'a> {
+	pub arms: Vec<(
+		MatchArm<'a>,
+		FatArrow,
+		Node<'a, Expression<'a>>,
+		Option<Comma>,
+	)>,
+}
+
+impl<'a> Parse<'a> for MatchArms<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		let mut arms = Vec::new();
+		loop {
+			let before = input.cursor.len();
+			let match_arm = input.parse();
+			let fat_arrow = input.parse();
+			let expression: Node<Expression> = input.parse();
+			let comma =
+				if matches!(*expression, Expression::ExpressionWithBlock(_)) || input.is_end() {
+					input.parse()
+				} else {
+					Some(input.parse())
+				};
+			arms.push((match_arm, fat_arrow, expression, comma));
+			// Errors were reported if nothing was consumed.
+			if input.is_end() || input.cursor.len() == before {
+				break Self { arms };
+			}
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(MatchArm, FatArrow, Expression, Option<Comma>)>::describe(w)
+	}
+}
+
+/// A match arm's pattern and optional guard, before its `=>`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct MatchArm<'a> {
+	pub outer_attributes: Vec<OuterAttribute<'a>>,
+	pub pattern: Pattern<'a>,
+	pub match_arm_guard: Option<MatchArmGuard<'a>>,
+}
+
+impl<'a> Parse<'a> for MatchArm<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			outer_attributes: input.parse(),
+			pattern: input.parse(),
+			match_arm_guard: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Vec<OuterAttribute>, Pattern, Option<MatchArmGuard>)>::describe(w)
+	}
+}
+
+/// `if condition` after a match arm's pattern.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct MatchArmGuard<'a> {
+	pub r#if: If,
+	pub expression: Node<'a, Expression<'a>>,
+}
+
+impl<'a> Parse<'a> for MatchArmGuard<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			r#if: input.parse(),
+			expression: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(If, Expression)>::describe(w)
+	}
+
+	fn first() -> First {
+		If::first()
+	}
+}
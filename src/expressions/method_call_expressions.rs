@@ -0,0 +1,50 @@
+//! [Method call expressions](https://doc.rust-lang.org/stable/reference/expressions/method-call-expr.html).
+
+use std::fmt::{self, Write};
+
+use crate::{
+	help::{catalog::Message, Expected},
+	io::{arena::Node, Input, Parse},
+	names::paths::PathExprSegment,
+	tokens::{delimiters::Parentheses, punctuation::Dot},
+};
+
+use super::{call_expressions::CallParams, is_postfix_end, limitations::NONE, Expression};
+
+/// `receiver.method(arguments)`, optionally with `::<…>` generic arguments.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct MethodCallExpression<'a> {
+	pub expression: Node<'a, Expression<'a>>,
+	pub dot: Dot,
+	pub path_expr_segment: PathExprSegment<'a>,
+	pub parentheses: Parentheses<'a, Option<CallParams<'a>>>,
+}
+
+impl<'a> Parse<'a> for MethodCallExpression<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		let (expression, (dot, path_expr_segment, parentheses)) =
+			input.right_aligned_left_recursive_parse(is_postfix_end::<NONE>);
+		Self {
+			expression,
+			dot,
+			path_expr_segment,
+			parentheses,
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-before")
+			.arg(
+				"item",
+				Expected::of::<(
+					Expression,
+					Dot,
+					PathExprSegment,
+					Parentheses<Option<CallParams>>,
+				)>(),
+			)
+			.arg("terminators", "(end|`=>`|`,`|`;`)")
+			.write(w)
+	}
+}
@@ -1,76 +1,727 @@
-use std::fmt::Write;
+//! [Operator expressions](https://doc.rust-lang.org/stable/reference/expressions/operator-expr.html).
+
+use std::{
+	fmt::{self, Write},
+	iter::Peekable,
+	marker::PhantomData,
+	vec,
+};
 
 use either::Either;
+use proc_macro2::{Delimiter, Spacing, Span};
 
 use crate::{
-	io::{Input, Parse},
+	help::{catalog::Message, codes::Code, Diagnostic, Expected},
+	io::{
+		arena::Node,
+		random_access::{self, TokenTree},
+		Input, Parse,
+	},
 	tokens::{
-		keywords::{As, Mut},
+		keywords::{
+			As, Async, Break, Else, For, If, Loop, Match, Move, Mut, Return, Unsafe, While,
+		},
 		punctuation::{
-			And, AndAnd, AndEq, Caret, CaretEq, Comma, Eq, EqEq, FatArrow, Ge, Gt, Le, Lt, Minus,
-			MinusEq, Ne, Not, Or, OrEq, OrOr, Percent, PercentEq, Plus, PlusEq, Question, Semi,
-			Shl, ShlEq, Shr, ShrEq, Slash, SlashEq, Star, StarEq,
+			And, AndAnd, AndEq, Caret, CaretEq, DotDot, DotDotDot, DotDotEq, Eq, EqEq, Ge, Gt, Le,
+			Lt, Minus, MinusEq, Ne, Not, Or, OrEq, OrOr, PathSep, Percent, PercentEq, Plus, PlusEq,
+			Question, Shl, ShlEq, Shr, ShrEq, Slash, SlashEq, Star, StarEq,
 		},
 	},
 	type_system::types::TypeNoBounds,
 };
 
 use super::{
+	is_postfix_end,
 	limitations::{ExpressionLimitation, NONE},
-	Expression,
+	range_expressions::RangeExpression,
+	Expression, ExpressionWithoutBlock, ExpressionWithoutBlockContent,
 };
 
+/// A prefix, postfix or infix operator expression.
+#[derive(Debug, Clone)]
+#[allow(missing_docs, clippy::large_enum_variant)]
 pub enum OperatorExpression<'a, LIMITATION: ExpressionLimitation = NONE> {
-	BorrowExpression(BorrowExpression<'a>),
-	DereferenceExpression(DereferenceExpression<'a>),
-	ErrorPropagationExpression(ErrorPropagationExpression<'a>),
-	NegationExpression(NegationExpression<'a>),
-	ArithmeticOrLogicalExpression(ArithmeticOrLogicalExpression<'a>),
-	ComparisonExpression(ComparisonExpression<'a>),
-	LazyBooleanExpression(LazyBooleanExpression<'a>),
-	TypeCastExpression(TypeCastExpression<'a>),
-	AssignmentExpression(AssignmentExpression<'a>),
-	CompoundAssignmentExpression(CompoundAssignmentExpression<'a>),
+	BorrowExpression(BorrowExpression<'a, LIMITATION>),
+	DereferenceExpression(DereferenceExpression<'a, LIMITATION>),
+	ErrorPropagationExpression(ErrorPropagationExpression<'a, LIMITATION>),
+	NegationExpression(NegationExpression<'a, LIMITATION>),
+	ArithmeticOrLogicalExpression(ArithmeticOrLogicalExpression<'a, LIMITATION>),
+	ComparisonExpression(ComparisonExpression<'a, LIMITATION>),
+	LazyBooleanExpression(LazyBooleanExpression<'a, LIMITATION>),
+	TypeCastExpression(TypeCastExpression<'a, LIMITATION>),
+	AssignmentExpression(AssignmentExpression<'a, LIMITATION>),
+	CompoundAssignmentExpression(CompoundAssignmentExpression<'a, LIMITATION>),
 }
 
 impl<'a, LIMITATION: ExpressionLimitation> Parse<'a> for OperatorExpression<'a, LIMITATION> {
 	fn parse(input: &mut Input<'a>) -> Self {
-		//TODO: snake_case
-		if let (BorrowExpression, Ok(())) = input.try_parse() {
-			Self::BorrowExpression(BorrowExpression)
-		} else if let (DereferenceExpression, Ok(())) = input.try_parse() {
-			Self::DereferenceExpression(DereferenceExpression)
-		} else if let (ErrorPropagationExpression, Ok(())) = input.try_parse() {
-			Self::ErrorPropagationExpression(ErrorPropagationExpression)
-		} else if let (NegationExpression, Ok(())) = input.try_parse() {
-			Self::NegationExpression(NegationExpression)
-		} else if let (ArithmeticOrLogicalExpression, Ok(())) = input.try_parse() {
-			Self::ArithmeticOrLogicalExpression(ArithmeticOrLogicalExpression)
-		} else if let (ComparisonExpression, Ok(())) = input.try_parse() {
-			Self::ComparisonExpression(ComparisonExpression)
-		} else if let Some((LazyBooleanExpression, Ok(()))) =
-			(!LIMITATION::EXCEPT_LAZY_BOOLEAN_OPERATOR_EXPRESSION).then_some(|| input.try_parse())
-		{
-			Self::LazyBooleanExpression(LazyBooleanExpression)
-		} else if let (TypeCastExpression, Ok(())) = input.try_parse() {
-			Self::TypeCastExpression(TypeCastExpression)
-		} else if let (AssignmentExpression, Ok(())) = input.try_parse() {
-			Self::AssignmentExpression(AssignmentExpression)
-		} else if let (CompoundAssignmentExpression, Ok(())) = input.try_parse() {
-			Self::CompoundAssignmentExpression(CompoundAssignmentExpression)
+		let mut attempt = input.clone();
+		match parse_operators(&mut attempt) {
+			Some(Either::Left(operator_expression)) => {
+				*input = attempt;
+				operator_expression
+			}
+			_ => input.error_expected(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-operator-expression").write(w)
+	}
+}
+
+/// Parses the [`OperatorExpression`] or [`RangeExpression`] at `input`'s cursor, if any operator applies to the whole expression.
+///
+/// Both are climbed together, since range operators bind between `||` and assignments.
+/// Returns [`None`] without consuming anything otherwise.
+pub(crate) fn parse_operators<'a, LIMITATION: ExpressionLimitation>(
+	input: &mut Input<'a>,
+) -> Option<Either<OperatorExpression<'a, LIMITATION>, RangeExpression<'a, LIMITATION>>> {
+	let chain = Chain::scan::<LIMITATION>(input);
+	if chain.rest.is_empty() {
+		// Unary and postfix operators bind tighter than any infix one,
+		// so they only apply to the whole expression if there is none.
+		return if input.peek::<And>() || input.peek::<AndAnd>() {
+			Some(Either::Left(OperatorExpression::BorrowExpression(
+				input.parse(),
+			)))
+		} else if input.peek::<Star>() {
+			Some(Either::Left(OperatorExpression::DereferenceExpression(
+				input.parse(),
+			)))
+		} else if input.peek::<Minus>() || input.peek::<Not>() {
+			Some(Either::Left(OperatorExpression::NegationExpression(
+				input.parse(),
+			)))
+		} else if chain.first.last().is_some_and(|last| is_punct(last, '?')) {
+			// Only worth a try if the expression ends with one.
+			match input.try_parse() {
+				(error_propagation_expression, Ok(())) => Some(Either::Left(
+					OperatorExpression::ErrorPropagationExpression(error_propagation_expression),
+				)),
+				(_, Err(_)) => None,
+			}
+		} else {
+			None
+		};
+	}
+
+	let (left, operation) = {
+		let mut climber = Climber {
+			input: &*input,
+			rest: chain.rest.into_iter().peekable(),
+			limitation: PhantomData,
+		};
+		let first = (!chain.first.is_empty()).then(|| input.parse_exhaustively(chain.first));
+		climber.climb(first, 0)
+	};
+	input.advance(chain.len);
+	operation.map(|operation| operation.build(left))
+}
+
+impl<LIMITATION: ExpressionLimitation> Default for OperatorExpression<'_, LIMITATION> {
+	fn default() -> Self {
+		Self::ArithmeticOrLogicalExpression(ArithmeticOrLogicalExpression::default())
+	}
+}
+
+/// An operator that appears between the operands of an [`OperatorExpression`] or [`RangeExpression`].
+///
+/// Range operators may lack either operand.
+enum InfixOperator {
+	ArithmeticOrLogical(ArithmeticOrLogicalOp),
+	Comparison(ComparisonOp),
+	LazyBoolean(LazyBooleanOp),
+	TypeCast(As),
+	Range(RangeOp),
+	Assignment(Eq),
+	CompoundAssignment(CompoundAssignmentOp),
+}
+
+impl InfixOperator {
+	/// Higher binds tighter.
+	///
+	/// See <https://doc.rust-lang.org/stable/reference/expressions.html#expression-precedence>.
+	fn precedence(&self) -> u8 {
+		match self {
+			Self::Assignment(_) | Self::CompoundAssignment(_) => 1,
+			Self::Range(_) => 2,
+			Self::LazyBoolean(LazyBooleanOp::OrElse(_)) => 3,
+			Self::LazyBoolean(LazyBooleanOp::AndThen(_)) => 4,
+			Self::Comparison(_) => 5,
+			Self::ArithmeticOrLogical(ArithmeticOrLogicalOp::BitOr(_)) => 6,
+			Self::ArithmeticOrLogical(ArithmeticOrLogicalOp::BitXor(_)) => 7,
+			Self::ArithmeticOrLogical(ArithmeticOrLogicalOp::BitAnd(_)) => 8,
+			Self::ArithmeticOrLogical(
+				ArithmeticOrLogicalOp::Shl(_) | ArithmeticOrLogicalOp::Shr(_),
+			) => 9,
+			Self::ArithmeticOrLogical(
+				ArithmeticOrLogicalOp::Add(_) | ArithmeticOrLogicalOp::Sub(_),
+			) => 10,
+			Self::ArithmeticOrLogical(
+				ArithmeticOrLogicalOp::Mul(_)
+				| ArithmeticOrLogicalOp::Div(_)
+				| ArithmeticOrLogicalOp::Rem(_),
+			) => 11,
+			Self::TypeCast(_) => 12,
+		}
+	}
+
+	/// Operators that require parentheses to chain, along with the error to report if they aren't.
+	fn non_associative(&self) -> Option<(Message, Message, Code)> {
+		match self {
+			Self::Comparison(_) => Some((
+				Message::new("chained-comparison"),
+				Message::new("chained-comparison-note"),
+				Code::CHAINED_COMPARISON,
+			)),
+			Self::Range(_) => Some((
+				Message::new("chained-range"),
+				Message::new("chained-range-note"),
+				Code::CHAINED_RANGE,
+			)),
+			_ => None,
+		}
+	}
+}
+
+/// `..` or `..=`.
+enum RangeOp {
+	DotDot(DotDot),
+	DotDotEq(DotDotEq),
+}
+
+impl Parse<'_> for RangeOp {
+	#[allow(clippy::same_functions_in_if_condition)]
+	fn parse(input: &mut Input<'_>) -> Self {
+		if let (dot_dot, Ok(())) = input.try_parse() {
+			Self::DotDot(dot_dot)
+		} else if let (dot_dot_eq, Ok(())) = input.try_parse() {
+			Self::DotDotEq(dot_dot_eq)
+		} else {
+			input.error_expected()
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<Either<DotDot, DotDotEq>>::describe(w)
+	}
+}
+
+impl Default for RangeOp {
+	fn default() -> Self {
+		Self::DotDot(DotDot::default())
+	}
+}
+
+impl Parse<'_> for InfixOperator {
+	#[allow(clippy::same_functions_in_if_condition)]
+	fn parse(input: &mut Input<'_>) -> Self {
+		// Spacing tells e.g. `&&` apart from `&`, so the order here doesn't matter.
+		if let (compound_assignment_op, Ok(())) = input.try_parse() {
+			Self::CompoundAssignment(compound_assignment_op)
+		} else if let (lazy_boolean_op, Ok(())) = input.try_parse() {
+			Self::LazyBoolean(lazy_boolean_op)
+		} else if let (comparison_op, Ok(())) = input.try_parse() {
+			Self::Comparison(comparison_op)
+		} else if let (arithmetic_or_logical_op, Ok(())) = input.try_parse() {
+			Self::ArithmeticOrLogical(arithmetic_or_logical_op)
+		} else if let (r#as, Ok(())) = input.try_parse() {
+			Self::TypeCast(r#as)
+		} else if let (range_op, Ok(())) = input.try_parse() {
+			Self::Range(range_op)
+		} else if let (eq, Ok(())) = input.try_parse() {
+			Self::Assignment(eq)
 		} else {
 			input.error_expected()
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-infix-operator").write(w)
+	}
 }
 
-pub struct BorrowExpression<'a> {
+impl Default for InfixOperator {
+	fn default() -> Self {
+		Self::ArithmeticOrLogical(ArithmeticOrLogicalOp::default())
+	}
+}
+
+/// How many slots the invisible group at `input`'s cursor spans, if it's a whole expression without operators or postfixes around it,
+/// like a `macro_rules!` capture `$e` on its own.
+///
+/// Only such a group is parsed as one expression regardless of what's inside, like by rustc.
+pub(crate) fn invisible_group_len<LIMITATION: ExpressionLimitation>(
+	input: &Input<'_>,
+) -> Option<usize> {
+	let group = input
+		.cursor
+		.first()
+		.filter(|first| is_group(first, Delimiter::None))?;
+	let chain = Chain::scan::<LIMITATION>(input);
+	(chain.rest.is_empty() && chain.len == group.flat_len()).then_some(chain.len)
+}
+
+/// An [`OperatorExpression`]'s or [`RangeExpression`]'s operands, split at its top-level infix operators.
+///
+/// Operands are only token ranges here. Since none of them contains an infix operator,
+/// parsing them can't recurse back into the same chain.
+/// They are empty where a range operator lacks one.
+struct Chain<'a> {
+	first: &'a [TokenTree],
+	/// Each operator, with its tokens and the operand to its right.
+	/// For [`InfixOperator::TypeCast`], that's a [`TypeNoBounds`].
+	rest: Vec<(InfixOperator, &'a [TokenTree], &'a [TokenTree])>,
+	/// How many slots the whole chain spans.
+	len: usize,
+}
+
+impl<'a> Chain<'a> {
+	fn scan<LIMITATION: ExpressionLimitation>(input: &Input<'a>) -> Self {
+		let tokens = input.cursor;
+		// Each level of a postfix chain is a prefix of the level above, so it's only scanned once.
+		if input.memo.is_some_and(|memo| memo.is_operand(tokens)) {
+			return Self {
+				first: tokens,
+				rest: Vec::new(),
+				len: tokens.len(),
+			};
+		}
+		let chain = Self::scan_operators::<LIMITATION>(input);
+		if let Some(memo) = input
+			.memo
+			.filter(|_| chain.rest.is_empty() && chain.len == tokens.len())
+		{
+			memo.record_operand(tokens);
+		}
+		chain
+	}
+
+	fn scan_operators<LIMITATION: ExpressionLimitation>(input: &Input<'a>) -> Self {
+		let tokens = input.cursor;
+		let mut chain = Self {
+			first: &[],
+			rest: Vec::new(),
+			len: 0,
+		};
+		let mut operator = None::<(InfixOperator, &'a [TokenTree])>;
+		let mut operand_start = 0;
+		let mut expects_operand = true;
+		let mut i = 0;
+
+		while i < tokens.len() {
+			let here = input.with_cursor(&tokens[i..]);
+			let is_range = here.peek::<DotDot>() || here.peek::<DotDotEq>();
+			if expects_operand && !is_range {
+				if matches!(operator, Some((InfixOperator::Range(_), _)))
+					&& (is_postfix_end::<LIMITATION>(&here) || here.peek::<Else>())
+				{
+					// Ranges may lack their end.
+					break;
+				} else if here.peek::<Or>()
+					|| here.peek::<OrOr>()
+					|| here.peek::<Move>()
+					|| here.peek::<Return>()
+					|| here.peek::<Break>()
+				{
+					// Closures and jumps extend as far to the right as possible.
+					i += here.find_end(is_postfix_end::<LIMITATION>);
+					break;
+				} else if here.peek::<If>()
+					|| here.peek::<Match>()
+					|| here.peek::<While>()
+					|| here.peek::<For>()
+					|| here.peek::<Loop>()
+					|| here.peek::<Unsafe>()
+					|| here.peek::<Async>()
+				{
+					i = skip_block_like(tokens, i);
+					expects_operand = false;
+				} else if is_punct(&tokens[i], '<') {
+					i = random_access::skip_angle_brackets(tokens, i);
+					expects_operand = false;
+				} else if ['&', '*', '-', '!']
+					.into_iter()
+					.any(|ch| is_punct(&tokens[i], ch))
+				{
+					i += 1;
+				} else {
+					i += tokens[i].flat_len();
+					expects_operand = false;
+				}
+				continue;
+			}
+
+			if is_postfix_end::<LIMITATION>(&here)
+				|| here.peek::<Else>()
+				|| here.peek::<DotDotDot>()
+				|| (LIMITATION::EXCEPT_LAZY_BOOLEAN_OPERATOR_EXPRESSION
+					&& (here.peek::<AndAnd>() || here.peek::<OrOr>()))
+			{
+				break;
+			} else if here.peek::<PathSep>()
+				&& tokens.get(i + 2).is_some_and(|tt| is_punct(tt, '<'))
+			{
+				i = random_access::skip_angle_brackets(tokens, i + 2);
+				continue;
+			}
+
+			let mut rest = here.clone();
+			// Only finds the operators, so whatever fails here isn't an alternative of the expression.
+			if let (next_operator, Ok(())) = input
+				.diagnostics
+				.probing(|| rest.try_parse::<InfixOperator>())
+			{
+				let operand = &tokens[operand_start..i];
+				let operator_len = here.cursor.len() - rest.cursor.len();
+				let operator_tokens = &tokens[i..i + operator_len];
+				match operator.replace((next_operator, operator_tokens)) {
+					None => chain.first = operand,
+					Some((previous, previous_tokens)) => {
+						chain.rest.push((previous, previous_tokens, operand))
+					}
+				}
+
+				i += operator_len;
+				operand_start = i;
+				if let Some((InfixOperator::TypeCast(_), _)) = operator {
+					i = skip_type(tokens, i);
+				} else {
+					expects_operand = true;
+				}
+			} else {
+				i += tokens[i].flat_len();
+			}
+		}
+
+		let operand = &tokens[operand_start..i];
+		match operator {
+			None => chain.first = operand,
+			Some((previous, previous_tokens)) => {
+				chain.rest.push((previous, previous_tokens, operand))
+			}
+		}
+		chain.len = i;
+		chain
+	}
+}
+
+fn is_punct(token_tree: &TokenTree, ch: char) -> bool {
+	matches!(token_tree, TokenTree::Punct(punct) if punct.as_char() == ch)
+}
+
+fn is_joint_punct(token_tree: &TokenTree, ch: char) -> bool {
+	matches!(token_tree, TokenTree::Punct(punct) if punct.as_char() == ch && punct.spacing() == Spacing::Joint)
+}
+
+fn is_group(token_tree: &TokenTree, delimiter: Delimiter) -> bool {
+	matches!(token_tree, TokenTree::Group(group) if group.delimiter == delimiter)
+}
+
+fn is_ident(token_tree: &TokenTree, text: &str) -> bool {
+	matches!(token_tree, TokenTree::Ident(ident) if ident == text)
+}
+
+/// Returns the index after the (`else`-chained) block of the `if`, `match`, loop, `unsafe` or `async` expression at `start`.
+fn skip_block_like(tokens: &[TokenTree], start: usize) -> usize {
+	let mut i = start;
+	loop {
+		match random_access::trees(&tokens[i..]).find(|(_, tt)| is_group(tt, Delimiter::Brace)) {
+			Some((offset, brace)) => i += offset + brace.flat_len(),
+			None => return tokens.len(),
+		}
+
+		match tokens.get(i) {
+			Some(tt) if is_ident(tt, "else") => i += 1,
+			_ => return i,
+		}
+	}
+}
+
+/// Returns the index after the [`TypeNoBounds`] at `start`.
+fn skip_type(tokens: &[TokenTree], start: usize) -> usize {
+	let mut i = start;
+	let mut expects_segment = true;
+	while let Some(token_tree) = tokens.get(i) {
+		let next = tokens.get(i + 1);
+		match token_tree {
+			TokenTree::Punct(punct)
+				if expects_segment && matches!(punct.as_char(), '&' | '*' | '!') =>
+			{
+				i += 1
+			}
+			// Lifetime.
+			TokenTree::Punct(punct) if expects_segment && punct.as_char() == '\'' => i += 2,
+			TokenTree::Punct(punct) if punct.as_char() == '<' => {
+				i = random_access::skip_angle_brackets(tokens, i);
+				expects_segment = false;
+			}
+			TokenTree::Punct(_)
+				if (is_joint_punct(token_tree, ':')
+					&& next.is_some_and(|tt| is_punct(tt, ':')))
+					|| (is_joint_punct(token_tree, '-')
+						&& next.is_some_and(|tt| is_punct(tt, '>'))) =>
+			{
+				i += 2;
+				expects_segment = true;
+			}
+			TokenTree::Ident(ident)
+				if ["mut", "const", "dyn", "unsafe", "extern", "fn", "for"]
+					.into_iter()
+					.any(|keyword| ident == keyword) =>
+			{
+				i += 1
+			}
+			TokenTree::Literal(_) if expects_segment => i += 1, // ABI
+			TokenTree::Ident(_) | TokenTree::Group(_) if expects_segment => {
+				i += token_tree.flat_len();
+				expects_segment = false;
+			}
+			// `Fn(…)`
+			TokenTree::Group(group) if group.delimiter == Delimiter::Parenthesis => {
+				i += token_tree.flat_len()
+			}
+			_ => break,
+		}
+	}
+	i
+}
+
+/// An infix operation with its right operand, waiting for its left one.
+#[allow(clippy::large_enum_variant)]
+enum Operation<'a, LIMITATION: ExpressionLimitation> {
+	ArithmeticOrLogical(ArithmeticOrLogicalOp, Node<'a, Expression<'a, LIMITATION>>),
+	Comparison(ComparisonOp, Node<'a, Expression<'a, LIMITATION>>),
+	LazyBoolean(LazyBooleanOp, Node<'a, Expression<'a, LIMITATION>>),
+	TypeCast(As, TypeNoBounds<'a>),
+	Range(RangeOp, Option<Node<'a, Expression<'a, LIMITATION>>>),
+	Assignment(Eq, Node<'a, Expression<'a, LIMITATION>>),
+	CompoundAssignment(CompoundAssignmentOp, Node<'a, Expression<'a, LIMITATION>>),
+}
+
+impl<'a, LIMITATION: ExpressionLimitation> Operation<'a, LIMITATION> {
+	/// Only [`Operation::Range`] accepts a missing `left` operand.
+	/// (Otherwise, it has already been reported.)
+	fn build(
+		self,
+		left: Option<Node<'a, Expression<'a, LIMITATION>>>,
+	) -> Either<OperatorExpression<'a, LIMITATION>, RangeExpression<'a, LIMITATION>> {
+		if let Self::Range(op, end) = self {
+			return Either::Right(match (left, op, end) {
+				(Some(start), RangeOp::DotDot(dot_dot), Some(end)) => RangeExpression::RangeExpr {
+					start,
+					dot_dot,
+					end,
+				},
+				(Some(start), RangeOp::DotDot(dot_dot), None) => {
+					RangeExpression::RangeFromExpr { start, dot_dot }
+				}
+				(None, RangeOp::DotDot(dot_dot), Some(end)) => {
+					RangeExpression::RangeToExpr { dot_dot, end }
+				}
+				(None, RangeOp::DotDot(dot_dot), None) => {
+					RangeExpression::RangeFullExpr { dot_dot }
+				}
+				// A missing end has already been reported.
+				(Some(start), RangeOp::DotDotEq(dot_dot_eq), end) => {
+					RangeExpression::RangeInclusiveExpr {
+						start,
+						dot_dot_eq,
+						end: end.unwrap_or_default(),
+					}
+				}
+				(None, RangeOp::DotDotEq(dot_dot_eq), end) => {
+					RangeExpression::RangeToInclusiveExpr {
+						dot_dot_eq,
+						end: end.unwrap_or_default(),
+					}
+				}
+			});
+		}
+
+		let left = left.unwrap_or_default();
+		Either::Left(match self {
+			Self::ArithmeticOrLogical(op, right) => {
+				OperatorExpression::ArithmeticOrLogicalExpression(ArithmeticOrLogicalExpression {
+					left,
+					op,
+					right,
+				})
+			}
+			Self::Comparison(op, right) => {
+				OperatorExpression::ComparisonExpression(ComparisonExpression { left, op, right })
+			}
+			Self::LazyBoolean(op, right) => {
+				OperatorExpression::LazyBooleanExpression(LazyBooleanExpression { left, op, right })
+			}
+			Self::TypeCast(r#as, type_no_bounds) => {
+				OperatorExpression::TypeCastExpression(TypeCastExpression {
+					expression: left,
+					r#as,
+					type_no_bounds,
+				})
+			}
+			Self::Assignment(eq, right) => {
+				OperatorExpression::AssignmentExpression(AssignmentExpression { left, eq, right })
+			}
+			Self::CompoundAssignment(op, right) => {
+				OperatorExpression::CompoundAssignmentExpression(CompoundAssignmentExpression {
+					left,
+					op,
+					right,
+				})
+			}
+			Self::Range(..) => unreachable!("handled above"),
+		})
+	}
+
+	fn build_expression(
+		self,
+		input: &Input<'a>,
+		left: Option<Node<'a, Expression<'a, LIMITATION>>>,
+	) -> Node<'a, Expression<'a, LIMITATION>> {
+		Node::new(
+			input,
+			Expression::ExpressionWithoutBlock(ExpressionWithoutBlock {
+				outer_attributes: Vec::new(),
+				variant: match self.build(left) {
+					Either::Left(operator_expression) => {
+						ExpressionWithoutBlockContent::OperatorExpression(operator_expression)
+					}
+					Either::Right(range_expression) => {
+						ExpressionWithoutBlockContent::RangeExpression(range_expression)
+					}
+				},
+			}),
+		)
+	}
+}
+
+/// Precedence climbing over a [`Chain`].
+struct Climber<'a, 'b, LIMITATION: ExpressionLimitation> {
+	input: &'b Input<'a>,
+	#[allow(clippy::type_complexity)]
+	rest: Peekable<vec::IntoIter<(InfixOperator, &'a [TokenTree], &'a [TokenTree])>>,
+	limitation: PhantomData<LIMITATION>,
+}
+
+impl<'a, LIMITATION: ExpressionLimitation> Climber<'a, '_, LIMITATION> {
+	/// Consumes operators that bind at least as tightly as `min_precedence`.
+	///
+	/// The outermost operation is returned separately, so that the caller can choose its limitation.
+	fn climb(
+		&mut self,
+		mut left: Option<Node<'a, Expression<'a, LIMITATION>>>,
+		min_precedence: u8,
+	) -> (
+		Option<Node<'a, Expression<'a, LIMITATION>>>,
+		Option<Operation<'a, LIMITATION>>,
+	) {
+		let mut pending = None::<Operation<'a, LIMITATION>>;
+		// The precedence of the previous operator on this level, if it's non-associative.
+		// Tighter operators in between were consumed by `climb_right`, so this also catches `a == b + c == d`.
+		let mut non_associative = None;
+		while let Some(precedence) = self
+			.rest
+			.peek()
+			.map(|(operator, _, _)| operator.precedence())
+			.filter(|&precedence| precedence >= min_precedence)
+		{
+			if let Some(operation) = pending.take() {
+				left = Some(operation.build_expression(self.input, left));
+			}
+
+			let (operator, operator_tokens, right) = self.rest.next().expect("peeked");
+			if non_associative == Some(precedence) {
+				if let Some((message, note, code)) = operator.non_associative() {
+					self.input.diagnostics.push(
+						Diagnostic::error(
+							random_access::span(operator_tokens).unwrap_or_else(Span::call_site),
+							message,
+						)
+						.with_code(code)
+						.with_note(note)
+						.located(self.input, operator_tokens)
+						.recovered(),
+					);
+				}
+			}
+			non_associative = operator.non_associative().map(|_| precedence);
+
+			if left.is_none() && !matches!(operator, InfixOperator::Range(_)) {
+				self.input
+					.with_cursor(operator_tokens)
+					.report_expected::<Expression<LIMITATION>>();
+			}
+
+			let operation = match operator {
+				InfixOperator::ArithmeticOrLogical(op) => Operation::ArithmeticOrLogical(
+					op,
+					self.climb_right(operator_tokens, right, precedence + 1),
+				),
+				InfixOperator::Comparison(op) => Operation::Comparison(
+					op,
+					self.climb_right(operator_tokens, right, precedence + 1),
+				),
+				InfixOperator::LazyBoolean(op) => Operation::LazyBoolean(
+					op,
+					self.climb_right(operator_tokens, right, precedence + 1),
+				),
+				InfixOperator::TypeCast(r#as) => Operation::TypeCast(
+					r#as,
+					self.input.after(operator_tokens).parse_exhaustively(right),
+				),
+				// Only `..` ranges may lack their end.
+				InfixOperator::Range(op @ RangeOp::DotDot(_)) if right.is_empty() => {
+					Operation::Range(op, None)
+				}
+				InfixOperator::Range(op) => Operation::Range(
+					op,
+					Some(self.climb_right(operator_tokens, right, precedence + 1)),
+				),
+				// Assignments are right-associative.
+				InfixOperator::Assignment(eq) => {
+					Operation::Assignment(eq, self.climb_right(operator_tokens, right, precedence))
+				}
+				InfixOperator::CompoundAssignment(op) => Operation::CompoundAssignment(
+					op,
+					self.climb_right(operator_tokens, right, precedence),
+				),
+			};
+			pending = Some(operation);
+		}
+		(left, pending)
+	}
+
+	/// Parses the operand `tokens` after `operator_tokens`, along with any tighter operators that follow.
+	fn climb_right(
+		&mut self,
+		operator_tokens: &'a [TokenTree],
+		tokens: &'a [TokenTree],
+		min_precedence: u8,
+	) -> Node<'a, Expression<'a, LIMITATION>> {
+		let input = self.input.after(operator_tokens);
+		// A missing operand may still be the start of a range, as in `x = ..`.
+		let right = (!tokens.is_empty()).then(|| input.parse_exhaustively(tokens));
+		match self.climb(right, min_precedence) {
+			(Some(right), None) => right,
+			(left, Some(operation)) => operation.build_expression(self.input, left),
+			(None, None) => input.parse_exhaustively(tokens),
+		}
+	}
+}
+
+/// `&expression` or `&&expression`, optionally `mut`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub struct BorrowExpression<'a, LIMITATION: ExpressionLimitation = NONE> {
 	pub op: Either<And, AndAnd>,
 	pub r#mut: Option<Mut>,
-	pub expression: Box<Expression<'a>>,
+	pub expression: Node<'a, Expression<'a, LIMITATION>>,
 }
 
-impl<'a> Parse<'a> for BorrowExpression<'a> {
+impl<'a, LIMITATION: ExpressionLimitation> Parse<'a> for BorrowExpression<'a, LIMITATION> {
 	fn parse(input: &mut Input<'a>) -> Self {
 		Self {
 			op: input.parse(),
@@ -79,17 +730,30 @@ impl<'a> Parse<'a> for BorrowExpression<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		<(Either<And, AndAnd>, Option<Mut>, Expression)>::describe(w)
 	}
 }
 
-pub struct DereferenceExpression<'a> {
+impl<LIMITATION: ExpressionLimitation> Default for BorrowExpression<'_, LIMITATION> {
+	fn default() -> Self {
+		Self {
+			op: Either::Left(And::default()),
+			r#mut: None,
+			expression: Node::default(),
+		}
+	}
+}
+
+/// `*expression`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub struct DereferenceExpression<'a, LIMITATION: ExpressionLimitation = NONE> {
 	pub star: Star,
-	pub expression: Box<Expression<'a>>,
+	pub expression: Node<'a, Expression<'a, LIMITATION>>,
 }
 
-impl<'a> Parse<'a> for DereferenceExpression<'a> {
+impl<'a, LIMITATION: ExpressionLimitation> Parse<'a> for DereferenceExpression<'a, LIMITATION> {
 	fn parse(input: &mut Input<'a>) -> Self {
 		Self {
 			star: input.parse(),
@@ -97,42 +761,66 @@ impl<'a> Parse<'a> for DereferenceExpression<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		<(Star, Expression)>::describe(w)
 	}
 }
 
-pub struct ErrorPropagationExpression<'a> {
-	pub expression: Box<Expression<'a>>,
+impl<LIMITATION: ExpressionLimitation> Default for DereferenceExpression<'_, LIMITATION> {
+	fn default() -> Self {
+		Self {
+			star: Star::default(),
+			expression: Node::default(),
+		}
+	}
+}
+
+/// `expression?`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub struct ErrorPropagationExpression<'a, LIMITATION: ExpressionLimitation = NONE> {
+	pub expression: Node<'a, Expression<'a, LIMITATION>>,
 	pub question: Question,
 }
 
-impl<'a> Parse<'a> for ErrorPropagationExpression<'a> {
+impl<'a, LIMITATION: ExpressionLimitation> Parse<'a>
+	for ErrorPropagationExpression<'a, LIMITATION>
+{
 	fn parse(input: &mut Input<'a>) -> Self {
-		let (expression, question) = input.right_aligned_left_recursive_parse(|input| {
-			input.is_end()
-				|| input.peek::<FatArrow>()
-				|| input.peek::<Comma>()
-				|| input.peek::<Semi>()
-		});
+		let (expression, question) =
+			input.right_aligned_left_recursive_parse(is_postfix_end::<LIMITATION>);
 		Self {
 			expression,
 			question,
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
-		<(Expression, Question)>::describe(w)?;
-		w.write_str(" before (end|`=>`|`,`|`;`)")
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-before")
+			.arg("item", Expected::of::<(Expression, Question)>())
+			.arg("terminators", "(end|`=>`|`,`|`;`)")
+			.write(w)
+	}
+}
+
+impl<LIMITATION: ExpressionLimitation> Default for ErrorPropagationExpression<'_, LIMITATION> {
+	fn default() -> Self {
+		Self {
+			expression: Node::default(),
+			question: Question::default(),
+		}
 	}
 }
 
-pub struct NegationExpression<'a> {
+/// `-expression` or `!expression`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub struct NegationExpression<'a, LIMITATION: ExpressionLimitation = NONE> {
 	pub op: Either<Minus, Not>,
-	pub expression: Box<Expression<'a>>,
+	pub expression: Node<'a, Expression<'a, LIMITATION>>,
 }
 
-impl<'a> Parse<'a> for NegationExpression<'a> {
+impl<'a, LIMITATION: ExpressionLimitation> Parse<'a> for NegationExpression<'a, LIMITATION> {
 	fn parse(input: &mut Input<'a>) -> Self {
 		Self {
 			op: input.parse(),
@@ -140,31 +828,42 @@ impl<'a> Parse<'a> for NegationExpression<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		<(Either<Minus, Not>, Expression)>::describe(w)
 	}
 }
 
-pub struct ArithmeticOrLogicalExpression<'a> {
-	left: Box<Expression<'a>>,
-	op: ArithmeticOrLogicalOp,
-	right: Box<Expression<'a>>,
-}
-
-impl<'a> Parse<'a> for ArithmeticOrLogicalExpression<'a> {
-	fn parse(input: &mut Input<'a>) -> Self {
+impl<LIMITATION: ExpressionLimitation> Default for NegationExpression<'_, LIMITATION> {
+	fn default() -> Self {
 		Self {
-			left: input.parse(),
-			op: input.parse(),
-			right: input.parse(),
+			op: Either::Left(Minus::default()),
+			expression: Node::default(),
 		}
 	}
+}
 
-	fn describe(w: &mut dyn Write) {
-		<(Expression, ArithmeticOrLogicalOp, Expression)>::describe(w)
+/// `left op right` with an [`ArithmeticOrLogicalOp`].
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub struct ArithmeticOrLogicalExpression<'a, LIMITATION: ExpressionLimitation = NONE> {
+	pub left: Node<'a, Expression<'a, LIMITATION>>,
+	pub op: ArithmeticOrLogicalOp,
+	pub right: Node<'a, Expression<'a, LIMITATION>>,
+}
+
+impl<LIMITATION: ExpressionLimitation> Default for ArithmeticOrLogicalExpression<'_, LIMITATION> {
+	fn default() -> Self {
+		Self {
+			left: Node::default(),
+			op: ArithmeticOrLogicalOp::default(),
+			right: Node::default(),
+		}
 	}
 }
 
+/// `+`, `-`, `*`, `/`, `%`, `&`, `|`, `^`, `<<` or `>>`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub enum ArithmeticOrLogicalOp {
 	Add(Plus),
 	Sub(Minus),
@@ -179,6 +878,7 @@ pub enum ArithmeticOrLogicalOp {
 }
 
 impl Parse<'_> for ArithmeticOrLogicalOp {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut Input<'_>) -> Self {
 		if let (plus, Ok(())) = input.try_parse() {
 			Self::Add(plus)
@@ -205,8 +905,8 @@ impl Parse<'_> for ArithmeticOrLogicalOp {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
-		w.write_str("([+-*/%&|^]|`<<`|`>>`)")
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-arithmetic-or-logical-operator").write(w)
 	}
 }
 
@@ -216,26 +916,28 @@ impl Default for ArithmeticOrLogicalOp {
 	}
 }
 
-pub struct ComparisonExpression<'a> {
-	left: Box<Expression<'a>>,
-	op: ComparisonOp,
-	right: Box<Expression<'a>>,
+/// `left op right` with a [`ComparisonOp`].
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub struct ComparisonExpression<'a, LIMITATION: ExpressionLimitation = NONE> {
+	pub left: Node<'a, Expression<'a, LIMITATION>>,
+	pub op: ComparisonOp,
+	pub right: Node<'a, Expression<'a, LIMITATION>>,
 }
 
-impl<'a> Parse<'a> for ComparisonExpression<'a> {
-	fn parse(input: &mut Input<'a>) -> Self {
+impl<LIMITATION: ExpressionLimitation> Default for ComparisonExpression<'_, LIMITATION> {
+	fn default() -> Self {
 		Self {
-			left: input.parse(),
-			op: input.parse(),
-			right: input.parse(),
+			left: Node::default(),
+			op: ComparisonOp::default(),
+			right: Node::default(),
 		}
 	}
-
-	fn describe(w: &mut dyn Write) {
-		<(Expression, ComparisonOp, Expression)>::describe(w)
-	}
 }
 
+/// `==`, `!=`, `>`, `<`, `>=` or `<=`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub enum ComparisonOp {
 	Eq(EqEq),
 	Ne(Ne),
@@ -246,6 +948,7 @@ pub enum ComparisonOp {
 }
 
 impl Parse<'_> for ComparisonOp {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut Input<'_>) -> Self {
 		if let (eq_eq, Ok(())) = input.try_parse() {
 			Self::Eq(eq_eq)
@@ -264,8 +967,8 @@ impl Parse<'_> for ComparisonOp {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
-		w.write_str("(`==`|`!=`|`>`|`<`|`>=`|`<=`)")
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-comparison-operator").write(w)
 	}
 }
 
@@ -275,32 +978,35 @@ impl Default for ComparisonOp {
 	}
 }
 
-pub struct LazyBooleanExpression<'a> {
-	left: Box<Expression<'a>>,
-	op: LazyBooleanOp,
-	right: Box<Expression<'a>>,
+/// `left || right` or `left && right`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub struct LazyBooleanExpression<'a, LIMITATION: ExpressionLimitation = NONE> {
+	pub left: Node<'a, Expression<'a, LIMITATION>>,
+	pub op: LazyBooleanOp,
+	pub right: Node<'a, Expression<'a, LIMITATION>>,
 }
 
-impl<'a> Parse<'a> for LazyBooleanExpression<'a> {
-	fn parse(input: &mut Input<'a>) -> Self {
+impl<LIMITATION: ExpressionLimitation> Default for LazyBooleanExpression<'_, LIMITATION> {
+	fn default() -> Self {
 		Self {
-			left: input.parse(),
-			op: input.parse(),
-			right: input.parse(),
+			left: Node::default(),
+			op: LazyBooleanOp::default(),
+			right: Node::default(),
 		}
 	}
-
-	fn describe(w: &mut dyn Write) {
-		<(Expression, LazyBooleanOp, Expression)>::describe(w)
-	}
 }
 
+/// `||` or `&&`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub enum LazyBooleanOp {
 	OrElse(OrOr),
 	AndThen(AndAnd),
 }
 
 impl Parse<'_> for LazyBooleanOp {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut Input<'_>) -> Self {
 		if let (or_or, Ok(())) = input.try_parse() {
 			Self::OrElse(or_or)
@@ -311,77 +1017,77 @@ impl Parse<'_> for LazyBooleanOp {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
-		w.write_str("(`&&`|`||`)")
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-lazy-boolean-operator").write(w)
 	}
 }
 
 impl Default for LazyBooleanOp {
 	fn default() -> Self {
-		Self::Eq(EqEq::default())
+		Self::OrElse(OrOr::default())
 	}
 }
 
-pub struct TypeCastExpression<'a> {
-	expression: Box<Expression<'a>>,
-	r#as: As,
-	type_no_bounds: TypeNoBounds<'a>,
+/// `expression as Type`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub struct TypeCastExpression<'a, LIMITATION: ExpressionLimitation = NONE> {
+	pub expression: Node<'a, Expression<'a, LIMITATION>>,
+	pub r#as: As,
+	pub type_no_bounds: TypeNoBounds<'a>,
 }
 
-impl<'a> Parse<'a> for TypeCastExpression<'a> {
-	fn parse(input: &mut Input<'a>) -> Self {
+impl<LIMITATION: ExpressionLimitation> Default for TypeCastExpression<'_, LIMITATION> {
+	fn default() -> Self {
 		Self {
-			expression: input.parse(),
-			r#as: input.parse(),
-			type_no_bounds: input.parse(),
+			expression: Node::default(),
+			r#as: As::default(),
+			type_no_bounds: TypeNoBounds::default(),
 		}
 	}
-
-	fn describe(w: &mut dyn Write) {
-		<(Expression, As, TypeNoBounds)>::describe(w)
-	}
 }
 
-pub struct AssignmentExpression<'a> {
-	left: Box<Expression<'a>>,
-	eq: Eq,
-	right: Box<Expression<'a>>,
+/// `place = value`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub struct AssignmentExpression<'a, LIMITATION: ExpressionLimitation = NONE> {
+	pub left: Node<'a, Expression<'a, LIMITATION>>,
+	pub eq: Eq,
+	pub right: Node<'a, Expression<'a, LIMITATION>>,
 }
 
-impl<'a> Parse<'a> for AssignmentExpression<'a> {
-	fn parse(input: &mut Input<'a>) -> Self {
+impl<LIMITATION: ExpressionLimitation> Default for AssignmentExpression<'_, LIMITATION> {
+	fn default() -> Self {
 		Self {
-			left: input.parse(),
-			eq: input.parse(),
-			right: input.parse(),
+			left: Node::default(),
+			eq: Eq::default(),
+			right: Node::default(),
 		}
 	}
-
-	fn describe(w: &mut dyn Write) {
-		<(Expression, Eq, TypeNoBounds)>::describe(w)
-	}
 }
 
-pub struct CompoundAssignmentExpression<'a> {
-	left: Box<Expression<'a>>,
-	op: CompoundAssignmentOp,
-	right: Box<Expression<'a>>,
+/// `place op= value` with a [`CompoundAssignmentOp`].
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub struct CompoundAssignmentExpression<'a, LIMITATION: ExpressionLimitation = NONE> {
+	pub left: Node<'a, Expression<'a, LIMITATION>>,
+	pub op: CompoundAssignmentOp,
+	pub right: Node<'a, Expression<'a, LIMITATION>>,
 }
 
-impl<'a> Parse<'a> for CompoundAssignmentExpression<'a> {
-	fn parse(input: &mut Input<'a>) -> Self {
+impl<LIMITATION: ExpressionLimitation> Default for CompoundAssignmentExpression<'_, LIMITATION> {
+	fn default() -> Self {
 		Self {
-			left: input.parse(),
-			op: input.parse(),
-			right: input.parse(),
+			left: Node::default(),
+			op: CompoundAssignmentOp::default(),
+			right: Node::default(),
 		}
 	}
-
-	fn describe(w: &mut dyn Write) {
-		<(Expression, CompoundAssignmentOp, Expression)>::describe(w)
-	}
 }
 
+/// `+=`, `-=`, `*=`, `/=`, `%=`, `&=`, `|=`, `^=`, `<<=` or `>>=`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub enum CompoundAssignmentOp {
 	AddAssign(PlusEq),
 	SubAssign(MinusEq),
@@ -396,6 +1102,7 @@ pub enum CompoundAssignmentOp {
 }
 
 impl Parse<'_> for CompoundAssignmentOp {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut Input<'_>) -> Self {
 		if let (plus_eq, Ok(())) = input.try_parse() {
 			Self::AddAssign(plus_eq)
@@ -422,13 +1129,153 @@ impl Parse<'_> for CompoundAssignmentOp {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
-		w.write_str("([+-*/%&|^]|`<<`|`>>`)")
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-compound-assignment-operator").write(w)
 	}
 }
 
 impl Default for CompoundAssignmentOp {
 	fn default() -> Self {
-		Self::AddAssign(Plus::default())
+		Self::AddAssign(PlusEq::default())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{help::codes::Code, parse_str};
+
+	use super::{
+		ArithmeticOrLogicalExpression, ArithmeticOrLogicalOp, Expression, ExpressionWithoutBlock,
+		ExpressionWithoutBlockContent, InfixOperator, LazyBooleanExpression, LazyBooleanOp,
+		OperatorExpression,
+	};
+
+	fn precedence(source: &str) -> u8 {
+		parse_str(source, |parsing| {
+			let (operator, diagnostics): (InfixOperator, _) = parsing.parse();
+			assert!(diagnostics.is_empty(), "`{source}` isn't an operator");
+			operator.precedence()
+		})
+		.unwrap()
+	}
+
+	#[test]
+	fn precedence_table() {
+		// Tightest first, as in the reference.
+		let levels: &[&[&str]] = &[
+			&["as"],
+			&["*", "/", "%"],
+			&["+", "-"],
+			&["<<", ">>"],
+			&["&"],
+			&["^"],
+			&["|"],
+			&["==", "!=", "<", ">", "<=", ">="],
+			&["&&"],
+			&["||"],
+			&["..", "..="],
+			&[
+				"=", "+=", "-=", "*=", "/=", "%=", "&=", "|=", "^=", "<<=", ">>=",
+			],
+		];
+		for level in levels {
+			for operator in *level {
+				assert_eq!(precedence(operator), precedence(level[0]), "`{operator}`");
+			}
+		}
+		for pair in levels.windows(2) {
+			assert!(
+				precedence(pair[0][0]) > precedence(pair[1][0]),
+				"`{}` should bind tighter than `{}`",
+				pair[0][0],
+				pair[1][0],
+			);
+		}
+	}
+
+	fn operator<'e, 'a>(expression: &'e Expression<'a>) -> &'e OperatorExpression<'a> {
+		match expression {
+			Expression::ExpressionWithoutBlock(ExpressionWithoutBlock {
+				variant: ExpressionWithoutBlockContent::OperatorExpression(operator),
+				..
+			}) => operator,
+			other => panic!("not an operator expression: {other:?}"),
+		}
+	}
+
+	fn arithmetic<'e, 'a>(expression: &'e Expression<'a>) -> &'e ArithmeticOrLogicalExpression<'a> {
+		match operator(expression) {
+			OperatorExpression::ArithmeticOrLogicalExpression(arithmetic) => arithmetic,
+			other => panic!("not an arithmetic or logical expression: {other:?}"),
+		}
+	}
+
+	#[test]
+	fn tighter_operators_nest_deeper() {
+		parse_str("1 + 2 * 3", |parsing| {
+			let (expression, diagnostics): (Expression, _) = parsing.parse();
+			assert!(diagnostics.is_empty());
+			let sum = arithmetic(&expression);
+			assert!(matches!(sum.op, ArithmeticOrLogicalOp::Add(_)));
+			assert!(matches!(
+				arithmetic(&sum.right).op,
+				ArithmeticOrLogicalOp::Mul(_)
+			));
+		})
+		.unwrap();
+
+		parse_str("a || b && c", |parsing| {
+			let (expression, diagnostics): (Expression, _) = parsing.parse();
+			assert!(diagnostics.is_empty());
+			let OperatorExpression::LazyBooleanExpression(LazyBooleanExpression {
+				op, right, ..
+			}) = operator(&expression)
+			else {
+				panic!("not a lazy boolean expression: {expression:?}");
+			};
+			assert!(matches!(op, LazyBooleanOp::OrElse(_)));
+			assert!(matches!(
+				operator(right),
+				OperatorExpression::LazyBooleanExpression(LazyBooleanExpression {
+					op: LazyBooleanOp::AndThen(_),
+					..
+				})
+			));
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn equal_precedence_nests_left() {
+		parse_str("1 - 2 - 3", |parsing| {
+			let (expression, diagnostics): (Expression, _) = parsing.parse();
+			assert!(diagnostics.is_empty());
+			let outer = arithmetic(&expression);
+			assert!(matches!(outer.op, ArithmeticOrLogicalOp::Sub(_)));
+			assert!(matches!(
+				arithmetic(&outer.left).op,
+				ArithmeticOrLogicalOp::Sub(_)
+			));
+		})
+		.unwrap();
+	}
+
+	fn codes(source: &str) -> Vec<Option<Code>> {
+		parse_str(source, |parsing| {
+			let (_, diagnostics): (Expression, _) = parsing.parse();
+			diagnostics.codes()
+		})
+		.unwrap()
+	}
+
+	#[test]
+	fn non_associative_operators_require_parentheses() {
+		assert_eq!(codes("a == b == c"), [Some(Code::CHAINED_COMPARISON)]);
+		assert_eq!(codes("a < b > c"), [Some(Code::CHAINED_COMPARISON)]);
+		assert_eq!(codes("a..b..c"), [Some(Code::CHAINED_RANGE)]);
+
+		assert_eq!(codes("(a == b) == c"), []);
+		assert_eq!(codes("a == b && b == c"), []);
+		assert_eq!(codes("a == b + c"), []);
 	}
 }
@@ -1,21 +1,47 @@
+//! [Path expressions](https://doc.rust-lang.org/stable/reference/expressions/path-expr.html).
+
+use std::fmt::{self, Write};
+
 use crate::{
-	io::{Input, Parse},
+	io::{first::First, Input, Parse},
 	names::paths::{PathInExpression, QualifiedPathInExpression},
 };
 
+/// A path as expression, like `a::b` or `<T as Trait>::f`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs, clippy::large_enum_variant)]
 pub enum PathExpression<'a> {
 	PathInExpression(PathInExpression<'a>),
 	QualifiedPathInExpression(QualifiedPathInExpression<'a>),
 }
 
 impl<'a> Parse<'a> for PathExpression<'a> {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut Input<'a>) -> Self {
 		if let (pie, Ok(())) = input.try_parse() {
 			Self::PathInExpression(pie)
 		} else if let (qpie, Ok(())) = input.try_parse() {
 			Self::QualifiedPathInExpression(qpie)
 		} else {
-			todo!()
+			input.error_expected()
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		w.write_char('(')?;
+		PathInExpression::describe(w)?;
+		w.write_char('|')?;
+		QualifiedPathInExpression::describe(w)?;
+		w.write_char(')')
+	}
+
+	fn first() -> First {
+		PathInExpression::first().or(QualifiedPathInExpression::first())
+	}
+}
+
+impl Default for PathExpression<'_> {
+	fn default() -> Self {
+		Self::PathInExpression(PathInExpression::default())
+	}
 }
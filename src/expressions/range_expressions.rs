@@ -0,0 +1,78 @@
+//! [Range expressions](https://doc.rust-lang.org/stable/reference/expressions/range-expr.html).
+
+use std::fmt::{self, Write};
+
+use either::Either;
+
+use crate::{
+	help::catalog::Message,
+	io::{arena::Node, Input, Parse},
+	tokens::punctuation::{DotDot, DotDotEq},
+};
+
+use super::{
+	limitations::{ExpressionLimitation, NONE},
+	operator_expressions::parse_operators,
+	Expression,
+};
+
+/// `start..end`, `start..`, `..end`, `..`, `start..=end` or `..=end`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub enum RangeExpression<'a, LIMITATION: ExpressionLimitation = NONE> {
+	/// `start..end`
+	RangeExpr {
+		start: Node<'a, Expression<'a, LIMITATION>>,
+		dot_dot: DotDot,
+		end: Node<'a, Expression<'a, LIMITATION>>,
+	},
+	/// `start..`
+	RangeFromExpr {
+		start: Node<'a, Expression<'a, LIMITATION>>,
+		dot_dot: DotDot,
+	},
+	/// `..end`
+	RangeToExpr {
+		dot_dot: DotDot,
+		end: Node<'a, Expression<'a, LIMITATION>>,
+	},
+	/// `..`
+	RangeFullExpr { dot_dot: DotDot },
+	/// `start..=end`
+	RangeInclusiveExpr {
+		start: Node<'a, Expression<'a, LIMITATION>>,
+		dot_dot_eq: DotDotEq,
+		end: Node<'a, Expression<'a, LIMITATION>>,
+	},
+	/// `..=end`
+	RangeToInclusiveExpr {
+		dot_dot_eq: DotDotEq,
+		end: Node<'a, Expression<'a, LIMITATION>>,
+	},
+}
+
+impl<'a, LIMITATION: ExpressionLimitation> Parse<'a> for RangeExpression<'a, LIMITATION> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		// Range operators are climbed along with the other infix operators.
+		let mut attempt = input.clone();
+		match parse_operators(&mut attempt) {
+			Some(Either::Right(range_expression)) => {
+				*input = attempt;
+				range_expression
+			}
+			_ => input.error_expected(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-range-expression").write(w)
+	}
+}
+
+impl<LIMITATION: ExpressionLimitation> Default for RangeExpression<'_, LIMITATION> {
+	fn default() -> Self {
+		Self::RangeFullExpr {
+			dot_dot: DotDot::default(),
+		}
+	}
+}
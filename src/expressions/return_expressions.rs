@@ -0,0 +1,35 @@
+//! [`return` expressions](https://doc.rust-lang.org/stable/reference/expressions/return-expr.html).
+
+use std::fmt::{self, Write};
+
+use crate::{
+	io::{arena::Node, first::First, Input, Parse},
+	tokens::keywords::Return,
+};
+
+use super::Expression;
+
+/// `return`, with an optional value.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct ReturnExpression<'a> {
+	pub r#return: Return,
+	pub expression: Option<Node<'a, Expression<'a>>>,
+}
+
+impl<'a> Parse<'a> for ReturnExpression<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			r#return: input.parse(),
+			expression: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Return, Option<Expression>)>::describe(w)
+	}
+
+	fn first() -> First {
+		Return::first()
+	}
+}
@@ -0,0 +1,208 @@
+//! [Struct expressions](https://doc.rust-lang.org/stable/reference/expressions/struct-expr.html).
+
+use std::fmt::{self, Write};
+
+use crate::{
+	attributes::OuterAttribute,
+	identifiers::Identifier,
+	io::{arena::Node, first::First, Input, Parse},
+	names::paths::PathInExpression,
+	tokens::{
+		delimiters::Braces,
+		punctuation::{Colon, Comma, DotDot},
+	},
+};
+
+use super::{tuple_expressions::TupleIndex, Expression};
+
+/// Tuple and unit structs are constructed with [`CallExpression`](`super::call_expressions::CallExpression`)s
+/// and [`PathExpression`](`super::path_expressions::PathExpression`)s instead.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct StructExpression<'a> {
+	pub path_in_expression: PathInExpression<'a>,
+	pub braces: Braces<'a, Option<StructExprContents<'a>>>,
+}
+
+impl<'a> Parse<'a> for StructExpression<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			path_in_expression: input.parse(),
+			braces: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(PathInExpression, Braces<Option<StructExprContents>>)>::describe(w)
+	}
+
+	fn first() -> First {
+		PathInExpression::first()
+	}
+}
+
+/// The non-empty contents of a struct expression's braces.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub enum StructExprContents<'a> {
+	StructExprFields(StructExprFields<'a>),
+	StructBase(StructBase<'a>),
+}
+
+impl<'a> Parse<'a> for StructExprContents<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		if let Some(struct_base) = input.parse() {
+			Self::StructBase(struct_base)
+		} else {
+			Self::StructExprFields(input.parse())
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		w.write_char('(')?;
+		StructExprFields::describe(w)?;
+		w.write_char('|')?;
+		StructBase::describe(w)?;
+		w.write_char(')')
+	}
+}
+
+impl Default for StructExprContents<'_> {
+	fn default() -> Self {
+		Self::StructExprFields(StructExprFields::default())
+	}
+}
+
+/// Comma-separated field initializers, optionally followed by a [`StructBase`].
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct StructExprFields<'a> {
+	pub struct_expr_field: StructExprField<'a>,
+	pub rest: Vec<(Comma, StructExprField<'a>)>,
+	/// The trailing comma, possibly followed by a [`StructBase`].
+	pub struct_base: Option<(Comma, Option<StructBase<'a>>)>,
+}
+
+impl<'a> Parse<'a> for StructExprFields<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			struct_expr_field: input.parse(),
+			rest: input.parse(),
+			struct_base: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(
+			StructExprField,
+			Vec<(Comma, StructExprField)>,
+			Option<(Comma, Option<StructBase>)>,
+		)>::describe(w)
+	}
+}
+
+/// A field initializer, with its outer attributes.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct StructExprField<'a> {
+	pub outer_attributes: Vec<OuterAttribute<'a>>,
+	pub variant: StructExprFieldVariant<'a>,
+}
+
+impl<'a> Parse<'a> for StructExprField<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			outer_attributes: input.parse(),
+			variant: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Vec<OuterAttribute>, StructExprFieldVariant)>::describe(w)
+	}
+}
+
+/// `0: value`, `name: value` or the shorthand `name`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub enum StructExprFieldVariant<'a> {
+	TupleIndex {
+		tuple_index: TupleIndex,
+		colon: Colon,
+		expression: Node<'a, Expression<'a>>,
+	},
+	Identifier {
+		identifier: Identifier,
+		colon: Colon,
+		expression: Node<'a, Expression<'a>>,
+	},
+	Shorthand {
+		identifier: Identifier,
+	},
+}
+
+impl<'a> Parse<'a> for StructExprFieldVariant<'a> {
+	#[allow(clippy::same_functions_in_if_condition)]
+	fn parse(input: &mut Input<'a>) -> Self {
+		if let ((tuple_index, colon, expression), Ok(())) = input.try_parse() {
+			Self::TupleIndex {
+				tuple_index,
+				colon,
+				expression,
+			}
+		} else if let ((identifier, colon, expression), Ok(())) = input.try_parse() {
+			Self::Identifier {
+				identifier,
+				colon,
+				expression,
+			}
+		} else {
+			Self::Shorthand {
+				identifier: input.parse(),
+			}
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		w.write_char('(')?;
+		<(TupleIndex, Colon, Expression)>::describe(w)?;
+		w.write_char('|')?;
+		<(Identifier, Colon, Expression)>::describe(w)?;
+		w.write_char('|')?;
+		Identifier::describe(w)?;
+		w.write_char(')')
+	}
+}
+
+impl Default for StructExprFieldVariant<'_> {
+	fn default() -> Self {
+		Self::Shorthand {
+			identifier: Identifier::default(),
+		}
+	}
+}
+
+/// `..base`, which provides the remaining fields.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct StructBase<'a> {
+	pub dot_dot: DotDot,
+	pub expression: Node<'a, Expression<'a>>,
+}
+
+impl<'a> Parse<'a> for StructBase<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			dot_dot: input.parse(),
+			expression: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(DotDot, Expression)>::describe(w)
+	}
+
+	fn first() -> First {
+		DotDot::first()
+	}
+}
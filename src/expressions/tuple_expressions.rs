@@ -0,0 +1,167 @@
+//! [Tuple and tuple indexing expressions](https://doc.rust-lang.org/stable/reference/expressions/tuple-expr.html).
+
+use std::fmt::{self, Write};
+
+use proc_macro2::{Literal, Spacing};
+
+use crate::{
+	help::{catalog::Message, Expected},
+	io::{
+		arena::Node,
+		default_span,
+		first::First,
+		random_access::{self, TokenTree},
+		Input, Parse,
+	},
+	tokens::{
+		delimiters::Parentheses,
+		punctuation::{Comma, Dot},
+	},
+};
+
+use super::{is_postfix_end, limitations::NONE, Expression};
+
+/// `()` or parentheses around at least one top-level comma.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct TupleExpression<'a> {
+	pub parentheses: Parentheses<'a, Option<TupleElements<'a>>>,
+}
+
+impl<'a> Parse<'a> for TupleExpression<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		if !is_tuple(input.cursor) {
+			// That's a `GroupedExpression`.
+			return input.error_expected();
+		}
+		Self {
+			parentheses: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Parentheses::<Option<TupleElements>>::describe(w)
+	}
+
+	fn first() -> First {
+		Parentheses::<Option<TupleElements>>::first()
+	}
+}
+
+/// Whether the parentheses at the start of `tokens` enclose a tuple rather than a grouped expression.
+///
+/// This only looks at the top-level tokens, so that nested groups aren't parsed twice.
+pub(crate) fn is_tuple(tokens: &[TokenTree]) -> bool {
+	let Some(contents) = random_access::group_contents(tokens) else {
+		return false;
+	};
+	if random_access::is_end(contents) {
+		return true;
+	}
+
+	let is_punct = |token_tree: &TokenTree, ch: char| matches!(token_tree, TokenTree::Punct(punct) if punct.as_char() == ch);
+	let mut trees = random_access::trees(contents)
+		.map(|(_, token_tree)| token_tree)
+		.peekable();
+	// Commas between leading closure parameters don't count.
+	trees.next_if(|token_tree| matches!(token_tree, TokenTree::Ident(ident) if ident == "move"));
+	if trees
+		.next_if(|token_tree| {
+			matches!(token_tree, TokenTree::Punct(punct) if punct.as_char() == '|' && punct.spacing() == Spacing::Alone)
+		})
+		.is_some()
+	{
+		trees.find(|token_tree| is_punct(token_tree, '|'));
+	}
+	trees.any(|token_tree| is_punct(token_tree, ','))
+}
+
+/// At least one expression followed by a comma, optionally followed by one more expression.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct TupleElements<'a> {
+	pub elements: Vec<(Node<'a, Expression<'a>>, Comma)>,
+	pub expression: Option<Node<'a, Expression<'a>>>,
+}
+
+impl<'a> Parse<'a> for TupleElements<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		let mut elements = vec![input.parse()];
+		elements.extend(input.parse::<Vec<_>>());
+		Self {
+			elements,
+			expression: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(
+			(Expression, Comma),
+			Vec<(Expression, Comma)>,
+			Option<Expression>,
+		)>::describe(w)
+	}
+}
+
+/// `expression.0`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct TupleIndexingExpression<'a> {
+	pub expression: Node<'a, Expression<'a>>,
+	pub dot: Dot,
+	pub tuple_index: TupleIndex,
+}
+
+impl<'a> Parse<'a> for TupleIndexingExpression<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		let (expression, (dot, tuple_index)) =
+			input.right_aligned_left_recursive_parse(is_postfix_end::<NONE>);
+		Self {
+			expression,
+			dot,
+			tuple_index,
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-before")
+			.arg("item", Expected::of::<(Expression, Dot, TupleIndex)>())
+			.arg("terminators", "(end|`=>`|`,`|`;`)")
+			.write(w)
+	}
+}
+
+/// An unsuffixed decimal integer literal.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub struct TupleIndex {
+	pub literal: Literal,
+}
+
+impl Parse<'_> for TupleIndex {
+	fn parse(input: &mut Input<'_>) -> Self {
+		match input.cursor.first() {
+			Some(TokenTree::Literal(literal))
+				if literal.to_string().bytes().all(|b| b.is_ascii_digit()) =>
+			{
+				input.consume(1);
+				Self {
+					literal: literal.clone(),
+				}
+			}
+			_ => input.error_expected(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-tuple-index").write(w)
+	}
+}
+
+impl Default for TupleIndex {
+	fn default() -> Self {
+		let mut literal = Literal::usize_unsuffixed(0);
+		literal.set_span(default_span());
+		Self { literal }
+	}
+}
@@ -0,0 +1,31 @@
+//! [`_` expressions](https://doc.rust-lang.org/stable/reference/expressions/underscore-expr.html).
+
+use std::fmt::{self, Write};
+
+use crate::{
+	io::{first::First, Input, Parse},
+	tokens::punctuation::Underscore,
+};
+
+/// `_`, as in destructuring assignments.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct UnderscoreExpression {
+	pub underscore: Underscore,
+}
+
+impl Parse<'_> for UnderscoreExpression {
+	fn parse(input: &mut Input<'_>) -> Self {
+		Self {
+			underscore: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Underscore::describe(w)
+	}
+
+	fn first() -> First {
+		Underscore::first()
+	}
+}
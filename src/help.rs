@@ -1,18 +1,469 @@
+//! Diagnostics, and how they are reported.
+
 use std::{
-	cell::RefCell,
-	iter::{self, IntoIterator},
-	marker::PhantomData,
+	any::TypeId,
+	cell::{Cell, RefCell},
+	fmt::{self, Debug, Display, Write},
+	iter::IntoIterator,
+	mem,
+	ops::Range,
+	slice,
 	vec::Vec,
 };
 
+use proc_macro2::{
+	Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree as TokenTree2,
+};
+
+use crate::io::{random_access::TokenTree, Input, Parse};
+
+use self::{catalog::Message, codes::Code};
+
+pub mod catalog;
+pub mod codes;
+#[cfg(feature = "span-locations")]
+pub mod fix;
+#[cfg(feature = "span-locations")]
+pub mod json;
+#[cfg(feature = "span-locations")]
+pub mod render;
+
+/// The diagnostics of a parse, shared by all [`Input`]s into the same tokens.
 #[derive(Debug, Clone, Default)]
 pub struct DiagnosticsList<'a> {
 	diagnostics: RefCell<Vec<Diagnostic<'a>>>,
+	/// Errors of failed speculative attempts, to report those of the one that got furthest
+	/// or merge them into one "expected one of" error if all alternatives fail.
+	failures: RefCell<Vec<Failure<'a>>>,
+	/// How many failures were recorded in total, to tell which ones were recorded during the current parse.
+	recorded_failures: Cell<usize>,
+	/// The value of `recorded_failures` when the current parse began.
+	parse_start: Cell<usize>,
+	/// The value of `recorded_failures` when the innermost speculative attempt began,
+	/// which can't report failures from before it, since it may be abandoned.
+	attempt_start: Cell<usize>,
+	/// Whether failures are currently left alone, since only lookahead is being parsed.
+	probing: Cell<bool>,
+	/// [Memoized](`crate::io::memo::Memoization`) parses in progress, by marker type and start.
+	in_progress: RefCell<Vec<(TypeId, Start)>>,
+	/// The tokens being parsed, which positions are indices into.
+	buffer: Cell<Buffer<'a>>,
+}
+
+#[derive(Clone, Copy, Default)]
+struct Buffer<'a>(&'a [TokenTree]);
+
+impl Debug for Buffer<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Buffer({} tokens)", self.0.len())
+	}
+}
+
+#[derive(Debug, Clone)]
+struct Failure<'a> {
+	/// Where the failed attempt started.
+	start: Start,
+	index: usize,
+	/// The position of the attempt's first error, up to which it went well.
+	position: Option<usize>,
+	/// Whether the attempted [FIRST set](`Parse::first`) admitted the first token, so that its expectations are worth mentioning.
+	admitted: bool,
+	diagnostics: Vec<Diagnostic<'a>>,
+}
+
+impl Failure<'_> {
+	/// Whether the attempt got past its first token before failing.
+	fn is_committed(&self) -> bool {
+		self.position > Some(self.start.start)
+	}
+
+	/// What the attempt's errors expected, to tell whether two attempts failed the same way.
+	fn expectations(&self) -> Vec<String> {
+		self.diagnostics
+			.iter()
+			.flat_map(|diagnostic| diagnostic.expected.iter().map(ToString::to_string))
+			.collect()
+	}
+}
+
+/// Indices of the cursor's start and end in the buffer.
+type Start = Range<usize>;
+
+/// Where a [`Diagnostic`] is in the buffer, for merging and [pruning](`DiagnosticsList::prune`).
+#[derive(Debug, Clone)]
+struct Location {
+	/// Index of the token at [`span`](`Diagnostic::span`).
+	position: usize,
+	/// The token region that is broken, from where the failing parse started to the end of the tokens it was given.
+	region: Range<usize>,
 }
 
 impl<'a> DiagnosticsList<'a> {
+	/// Reports `diagnostic`.
 	pub fn push(&self, diagnostic: Diagnostic<'a>) {
-		self.diagnostics.borrow_mut().push(iter::once(diagnostic))
+		self.diagnostics.borrow_mut().push(diagnostic)
+	}
+
+	/// Whether nothing was reported.
+	pub fn is_empty(&self) -> bool {
+		self.diagnostics.borrow().is_empty()
+	}
+
+	/// Whether any of the diagnostics is an error.
+	pub fn has_errors(&self) -> bool {
+		self.diagnostics.borrow().iter().any(Diagnostic::is_error)
+	}
+
+	/// Whether any diagnostic pushed since `checkpoint` is an error that wasn't [recovered](`Diagnostic::recovered`) from.
+	pub(crate) fn has_errors_since(&self, checkpoint: usize) -> bool {
+		self.diagnostics.borrow()[checkpoint..]
+			.iter()
+			.any(|diagnostic| diagnostic.is_error() && !diagnostic.recovered)
+	}
+
+	pub(crate) fn checkpoint(&self) -> usize {
+		self.diagnostics.borrow().len()
+	}
+
+	/// Returns copies of all diagnostics that were pushed since `checkpoint`.
+	pub(crate) fn copy_since(&self, checkpoint: usize) -> Self {
+		Self {
+			diagnostics: RefCell::new(self.diagnostics.borrow()[checkpoint..].to_vec()),
+			..Self::default()
+		}
+	}
+
+	/// `::core::compile_error!` invocations for all errors, to append to a procedural macro's best-effort output.
+	///
+	/// Other diagnostics are left out, since there's no stable way to emit them.
+	pub fn to_compile_errors(&self) -> TokenStream {
+		self.diagnostics
+			.borrow()
+			.iter()
+			.filter(|diagnostic| diagnostic.is_error())
+			.flat_map(Diagnostic::to_compile_error)
+			.collect()
+	}
+
+	/// Leaves only root causes, sorted by position.
+	///
+	/// Errors within the rest of a token region that an earlier error already reported as broken
+	/// are follow-on errors of enclosing parses and are dropped, as are duplicates.
+	pub fn prune(&self) {
+		let mut diagnostics = self.diagnostics.borrow_mut();
+		let mut sorted: Vec<_> = diagnostics.drain(..).map(Diagnostic::rendered).collect();
+		// Broken regions that start at the same token were reported by the same parse, root cause first.
+		// Diagnostics without a position keep their order, after the others.
+		sorted.sort_by_key(|diagnostic| {
+			diagnostic
+				.location
+				.as_ref()
+				.map_or((true, 0), |location| (false, location.region.start))
+		});
+		for diagnostic in sorted {
+			let follow_on = diagnostic.is_error()
+				&& diagnostics
+					.iter()
+					.any(|earlier| earlier.is_error() && earlier.breaks(&diagnostic));
+			if !follow_on
+				&& !diagnostics
+					.iter()
+					.any(|earlier| earlier.duplicates(&diagnostic))
+			{
+				diagnostics.push(diagnostic);
+			}
+		}
+		diagnostics
+			.sort_by_key(|diagnostic| (diagnostic.location.is_none(), diagnostic.position()));
+	}
+
+	/// Ends the regions that diagnostics pushed since `checkpoint` report as broken before `rest`,
+	/// where parsing went on independently of them.
+	pub(crate) fn end_regions(&self, checkpoint: usize, rest: &'a [TokenTree]) {
+		let end = self.indices(rest).start;
+		for diagnostic in &mut self.diagnostics.borrow_mut()[checkpoint..] {
+			if let Some(location) = &mut diagnostic.location {
+				location.region.end = location.region.end.min(end).max(location.position);
+			}
+		}
+	}
+
+	/// Drops all diagnostics with `code`.
+	pub fn silence(&self, code: Code) {
+		self.diagnostics
+			.borrow_mut()
+			.retain(|diagnostic| diagnostic.code != Some(code))
+	}
+
+	/// The codes of all diagnostics, in order.
+	#[cfg(test)]
+	pub(crate) fn codes(&self) -> Vec<Option<Code>> {
+		self.diagnostics
+			.borrow()
+			.iter()
+			.map(|diagnostic| diagnostic.code)
+			.collect()
+	}
+
+	/// The messages of all diagnostics, in order.
+	#[cfg(test)]
+	pub(crate) fn messages(&self) -> Vec<String> {
+		self.diagnostics
+			.borrow()
+			.iter()
+			.map(|diagnostic| diagnostic.message.to_string())
+			.collect()
+	}
+
+	/// Removes and returns all diagnostics that were pushed since `checkpoint`.
+	pub(crate) fn split_off(&self, checkpoint: usize) -> Self {
+		Self {
+			diagnostics: RefCell::new(self.diagnostics.borrow_mut().split_off(checkpoint)),
+			..Self::default()
+		}
+	}
+
+	/// Makes `tokens` the buffer that positions are indices into, unless they are within it already.
+	///
+	/// Failures recorded in another buffer are forgotten, since their positions don't apply anymore.
+	pub(crate) fn enter(&self, tokens: &'a [TokenTree]) {
+		let buffer = self.buffer.get().0.as_ptr_range();
+		let range = tokens.as_ptr_range();
+		if range.start < buffer.start || range.end > buffer.end {
+			self.buffer.set(Buffer(tokens));
+			self.failures.borrow_mut().clear();
+		}
+	}
+
+	/// Indices of the start and end of `tokens` in the buffer, [entering](`DiagnosticsList::enter`) them first if needed.
+	fn indices(&self, tokens: &'a [TokenTree]) -> Range<usize> {
+		self.enter(tokens);
+		let start = self.buffer.get().0.as_ptr() as usize;
+		let index =
+			|token: *const TokenTree| (token as usize - start) / mem::size_of::<TokenTree>();
+		let range = tokens.as_ptr_range();
+		index(range.start)..index(range.end)
+	}
+
+	/// The number of failures recorded so far, to pass to [`DiagnosticsList::record_failure`] or [`DiagnosticsList::discard_failures`].
+	pub(crate) fn failures_checkpoint(&self) -> usize {
+		self.recorded_failures.get()
+	}
+
+	/// Marks the beginning of a speculative attempt, returning what to pass to [`DiagnosticsList::end_attempt`].
+	pub(crate) fn begin_attempt(&self) -> usize {
+		self.attempt_start.replace(self.recorded_failures.get())
+	}
+
+	pub(crate) fn end_attempt(&self, outer: usize) {
+		self.attempt_start.set(outer)
+	}
+
+	/// Remembers that an attempt starting at `cursor` failed with `diagnostics`.
+	///
+	/// Failures of nested attempts since `checkpoint` are forgotten, since this one replaces them.
+	pub(crate) fn record_failure(
+		&self,
+		cursor: &'a [TokenTree],
+		checkpoint: usize,
+		diagnostics: &Self,
+		admitted: bool,
+	) {
+		if self.probing.get() {
+			return;
+		}
+		let start = self.indices(cursor);
+		let diagnostics: Vec<_> = diagnostics
+			.diagnostics
+			.borrow()
+			.iter()
+			.filter(|diagnostic| diagnostic.is_error())
+			.cloned()
+			.collect();
+		self.discard_failures(checkpoint);
+		if !diagnostics.is_empty() {
+			let index = self.recorded_failures.get();
+			self.recorded_failures.set(index + 1);
+			self.failures.borrow_mut().push(Failure {
+				start,
+				index,
+				position: diagnostics.iter().filter_map(Diagnostic::position).min(),
+				admitted,
+				diagnostics,
+			});
+		}
+	}
+
+	/// Forgets all failures recorded since `checkpoint`, which was returned by [`DiagnosticsList::failures_checkpoint`].
+	pub(crate) fn discard_failures(&self, checkpoint: usize) {
+		self.failures
+			.borrow_mut()
+			.retain(|failure| failure.index < checkpoint)
+	}
+
+	/// Forgets the failures of alternatives the current parse tried at `cursor`, since another one succeeded there.
+	pub(crate) fn forget_failures(&self, cursor: &'a [TokenTree]) {
+		if self.probing.get() {
+			return;
+		}
+		let start = self.indices(cursor);
+		let parse_start = self.parse_start.get();
+		self.failures
+			.borrow_mut()
+			.retain(|failure| failure.start != start || failure.index < parse_start)
+	}
+
+	/// Calls `f` without recording or forgetting failures, for lookahead that isn't an alternative of the current parse.
+	pub(crate) fn probing<T>(&self, f: impl FnOnce() -> T) -> T {
+		let outer = self.probing.replace(true);
+		let result = f();
+		self.probing.set(outer);
+		result
+	}
+
+	/// Whether failures are currently [left alone](`DiagnosticsList::probing`).
+	pub(crate) fn is_probing(&self) -> bool {
+		self.probing.get()
+	}
+
+	/// Marks the beginning of a parse, returning what to pass to [`DiagnosticsList::end_parse`].
+	pub(crate) fn begin_parse(&self) -> usize {
+		self.parse_start.replace(self.recorded_failures.get())
+	}
+
+	pub(crate) fn end_parse(&self, outer: usize) {
+		self.parse_start.set(outer)
+	}
+
+	/// Marks a parse of `marker` at `cursor` as in progress, unless one already is.
+	///
+	/// Returns whether it was marked, in which case it must be ended with [`DiagnosticsList::end_progress`].
+	pub(crate) fn begin_progress(&self, marker: TypeId, cursor: &'a [TokenTree]) -> bool {
+		let key = (marker, self.indices(cursor));
+		let mut in_progress = self.in_progress.borrow_mut();
+		if in_progress.contains(&key) {
+			false
+		} else {
+			in_progress.push(key);
+			true
+		}
+	}
+
+	pub(crate) fn end_progress(&self) {
+		self.in_progress.borrow_mut().pop();
+	}
+
+	/// Replaces `diagnostic` with what the failures of attempts that started at `cursor` say instead.
+	///
+	/// If any attempt there got past its first token, the input most likely was meant to be that,
+	/// so the errors of the one that got furthest are returned as they are.
+	/// Otherwise, the expectations of the alternatives the current parse tried there and whose FIRST sets admitted the token
+	/// are merged into one "expected one of" error, since those describe `diagnostic`'s own expectation in more detail.
+	/// Their fixes are only kept if there's no other alternative to them.
+	pub(crate) fn merge_failures(
+		&self,
+		cursor: &'a [TokenTree],
+		diagnostic: Diagnostic<'a>,
+	) -> Vec<Diagnostic<'a>> {
+		if self.probing.get() {
+			return vec![diagnostic];
+		}
+		let start = self.indices(cursor);
+		let (parse_start, attempt_start) = (self.parse_start.get(), self.attempt_start.get());
+		let mut committed: Option<Failure<'a>> = None;
+		// Whether another attempt got just as far, but failed differently.
+		let mut ambiguous = false;
+		let mut alternatives = Vec::new();
+		self.failures.borrow_mut().retain(|failure| {
+			if failure.start != start || failure.index < attempt_start {
+				return true;
+			}
+			if failure.is_committed() {
+				match &committed {
+					Some(committed) if failure.position < committed.position => {}
+					Some(committed) if failure.position == committed.position => {
+						ambiguous |= failure.expectations() != committed.expectations();
+					}
+					_ => {
+						committed = Some(failure.clone());
+						ambiguous = false;
+					}
+				}
+			} else if failure.index >= parse_start && failure.admitted {
+				alternatives.extend(
+					failure
+						.diagnostics
+						.iter()
+						.filter(|diagnostic| !diagnostic.expected.is_empty())
+						.cloned(),
+				);
+			}
+			false
+		});
+		if let Some(committed) = committed {
+			// Whatever is parsed from here on to recover follows on from them.
+			let mut diagnostics = committed.diagnostics;
+			for diagnostic in &mut diagnostics {
+				if let Some(location) = &mut diagnostic.location {
+					location.region.start = location.region.start.min(start.start);
+				}
+				if ambiguous {
+					for suggestion in &mut diagnostic.suggestions {
+						suggestion.applicability = Applicability::MaybeIncorrect;
+					}
+				}
+			}
+			return diagnostics;
+		}
+
+		// None of the alternatives can take the keyword here, so it was most likely meant as identifier.
+		if let Some(keyword) = alternatives
+			.iter()
+			.position(|diagnostic| diagnostic.code == Some(Code::KEYWORD_AS_IDENTIFIER))
+		{
+			return vec![alternatives.swap_remove(keyword)];
+		}
+
+		let mut alternatives = alternatives.into_iter();
+		let Some(mut merged) = alternatives.next() else {
+			return vec![diagnostic];
+		};
+		let mut descriptions: Vec<String> =
+			merged.expected.iter().map(ToString::to_string).collect();
+		let mut conflicting = false;
+		for alternative in alternatives {
+			for expected in alternative.expected {
+				let description = expected.to_string();
+				if !descriptions.contains(&description) {
+					descriptions.push(description);
+					merged.expected.push(expected);
+				}
+			}
+			// Alternatives are at the same position, so the same text makes the same fix.
+			for suggestion in alternative.suggestions {
+				if !merged
+					.suggestions
+					.iter()
+					.any(|known| known.replacement == suggestion.replacement)
+				{
+					conflicting |= !merged.suggestions.is_empty();
+					merged.suggestions.push(suggestion);
+				}
+			}
+			if alternative.code != merged.code {
+				merged.code = Some(Code::UNEXPECTED_TOKEN);
+			}
+		}
+		if conflicting {
+			// At most one of the alternatives' fixes is right, so none of them can be applied blindly.
+			merged.suggestions.clear();
+		} else if merged.expected.len() > 1 {
+			for suggestion in &mut merged.suggestions {
+				suggestion.applicability = Applicability::MaybeIncorrect;
+			}
+		}
+		merged.message = Message::new(EXPECTED);
+		vec![merged]
 	}
 }
 
@@ -32,12 +483,433 @@ impl<'a> Extend<Diagnostic<'a>> for &DiagnosticsList<'a> {
 	}
 }
 
+/// The [`Message`] ID of expectation errors, whose arguments are filled in by [`Diagnostic::rendered`].
+const EXPECTED: &str = "expected";
+
+/// A message about the parsed tokens, usually an error.
+#[derive(Debug, Clone)]
 #[non_exhaustive]
 pub struct Diagnostic<'a> {
-	pub todo: PhantomData<&'a ()>,
+	/// How severe this is.
+	pub r#type: DiagnosticType,
+	/// What kind of problem this is, if it's one of loess's own.
+	pub code: Option<Code>,
+	/// Where the problem is.
+	pub span: Span,
+	/// What the problem is.
+	pub message: Message,
+	/// Descriptions of what would have been accepted at [`span`](`Diagnostic::span`).
+	pub expected: Vec<Expected>,
+	/// The token found at [`span`](`Diagnostic::span`) instead, or [`None`] at the end of input.
+	pub found: Option<&'a TokenTree>,
+	/// Secondary spans with explanations.
+	pub labels: Vec<Label>,
+	/// Further explanations, without spans.
+	pub notes: Vec<Message>,
+	/// Possible fixes.
+	pub suggestions: Vec<Suggestion>,
+	/// Whether parsing went on as if the problem weren't there.
+	recovered: bool,
+	/// Where this is in the buffer, to find the furthest of several failures and which errors follow on from it.
+	location: Option<Location>,
 }
 
+impl<'a> Diagnostic<'a> {
+	/// A diagnostic with only the essentials.
+	pub fn new(r#type: DiagnosticType, span: Span, message: impl Into<Message>) -> Self {
+		Self {
+			r#type,
+			code: None,
+			span,
+			message: message.into(),
+			expected: Vec::new(),
+			found: None,
+			labels: Vec::new(),
+			notes: Vec::new(),
+			suggestions: Vec::new(),
+			recovered: false,
+			location: None,
+		}
+	}
+
+	/// A [`DiagnosticType::Error`].
+	pub fn error(span: Span, message: impl Into<Message>) -> Self {
+		Self::new(DiagnosticType::Error, span, message)
+	}
+
+	/// A [`DiagnosticType::Warning`].
+	pub fn warning(span: Span, message: impl Into<Message>) -> Self {
+		Self::new(DiagnosticType::Warning, span, message)
+	}
+
+	/// An error that a `T` was expected at `input`'s cursor, but something else was found.
+	#[must_use]
+	pub fn expected<T: Parse<'a>>(input: &Input<'a>) -> Self {
+		Self::expected_one_of(input, vec![Expected::of::<T>()])
+	}
+
+	/// An error that any of `expected` was expected at `input`'s cursor, but something else was found.
+	#[must_use]
+	pub fn expected_one_of(input: &Input<'a>, expected: Vec<Expected>) -> Self {
+		Self::unrendered(input, expected).rendered()
+	}
+
+	/// Like [`Diagnostic::expected_one_of`], but leaves [`message`](`Diagnostic::message`) without arguments, since describing can be costly.
+	pub(crate) fn unrendered(input: &Input<'a>, expected: Vec<Expected>) -> Self {
+		let found = input.next_token();
+		let mut diagnostic = Self::error(input.expected_span(), Message::new(EXPECTED));
+		diagnostic.code = Some(Code::UNEXPECTED_TOKEN);
+		diagnostic.expected = expected;
+		diagnostic.found = found;
+		let region_end = input.diagnostics.indices(input.cursor).end;
+		let position = found.map_or(region_end, |found| {
+			input.diagnostics.indices(slice::from_ref(found)).start
+		});
+		diagnostic.location = Some(Location {
+			position,
+			region: position..region_end,
+		});
+		diagnostic
+	}
+
+	/// Locates the diagnostic at the start of `tokens` in `input`'s buffer, reporting their rest as broken.
+	#[must_use]
+	pub(crate) fn located(mut self, input: &Input<'a>, tokens: &'a [TokenTree]) -> Self {
+		let region = input.diagnostics.indices(tokens);
+		self.location = Some(Location {
+			position: region.start,
+			region,
+		});
+		self
+	}
+
+	fn position(&self) -> Option<usize> {
+		self.location.as_ref().map(|location| location.position)
+	}
+
+	/// Whether `other` is within the region `self` reports as broken.
+	fn breaks(&self, other: &Self) -> bool {
+		match (&self.location, &other.location) {
+			(Some(location), Some(other)) => {
+				(location.region.start..=location.region.end).contains(&other.position)
+			}
+			_ => false,
+		}
+	}
+
+	fn duplicates(&self, other: &Self) -> bool {
+		self.r#type == other.r#type
+			&& self.message == other.message
+			&& self.position() == other.position()
+			&& (self.location.is_some() || same_location(self.span, other.span))
+	}
+
+	/// Fills in the arguments of an expectation [`message`](`Diagnostic::message`) from [`expected`](`Diagnostic::expected`) and [`found`](`Diagnostic::found`),
+	/// through the [installed catalog](`catalog::Catalog::install`).
+	///
+	/// Errors recorded while [suspended](`Input::suspended`) are left unrendered until then.
+	///
+	/// # Panics
+	///
+	/// Iff a [`Parse::describe`] implementation errors while writing into a [`String`].
+	#[must_use]
+	pub fn rendered(mut self) -> Self {
+		if self.message.id != EXPECTED || !self.message.args.is_empty() || self.expected.is_empty()
+		{
+			return self;
+		}
+
+		let mut expected = String::new();
+		for (i, description) in self.expected.iter().enumerate() {
+			if i > 0 {
+				expected.push_str(", ");
+			}
+			write!(expected, "{description}").expect("infallible");
+		}
+		let mut found = String::new();
+		describe_found(&mut found, self.found).expect("infallible");
+		self.message = Message::new(if self.expected.len() > 1 {
+			"expected-one-of"
+		} else {
+			EXPECTED
+		})
+		.arg("expected", expected)
+		.arg("found", found);
+		self
+	}
+
+	/// Whether this is a [`DiagnosticType::Error`].
+	#[must_use]
+	pub fn is_error(&self) -> bool {
+		self.r#type == DiagnosticType::Error
+	}
+
+	/// Whether parsing went on as if the problem weren't there.
+	#[must_use]
+	pub fn is_recovered(&self) -> bool {
+		self.recovered
+	}
+
+	/// Marks this as a problem that parsing went on from, as if it weren't there.
+	///
+	/// Recovered errors are still errors, but don't fail [speculative attempts](`Parse::try_parse`).
+	#[must_use]
+	pub fn recovered(mut self) -> Self {
+		self.recovered = true;
+		self
+	}
+
+	/// Sets [`code`](`Diagnostic::code`).
+	#[must_use]
+	pub fn with_code(mut self, code: Code) -> Self {
+		self.code = Some(code);
+		self
+	}
+
+	/// Adds a [`Label`].
+	#[must_use]
+	pub fn with_label(mut self, span: Span, message: impl Into<Message>) -> Self {
+		self.labels.push(Label {
+			span,
+			message: message.into(),
+		});
+		self
+	}
+
+	/// Adds a note.
+	#[must_use]
+	pub fn with_note(mut self, note: impl Into<Message>) -> Self {
+		self.notes.push(note.into());
+		self
+	}
+
+	/// Adds a [`Suggestion`].
+	#[must_use]
+	pub fn with_suggestion(mut self, suggestion: Suggestion) -> Self {
+		self.suggestions.push(suggestion);
+		self
+	}
+
+	/// A `::core::compile_error!` invocation with this diagnostic's message and notes, spanned at its primary span.
+	#[must_use]
+	pub fn to_compile_error(&self) -> TokenStream {
+		let mut message = self.clone().rendered().message.to_string();
+		for note in &self.notes {
+			write!(message, "\n= note: {note}").expect("infallible");
+		}
+
+		let span = self.span;
+		let mut message = Literal::string(&message);
+		message.set_span(span);
+		let body = Group::new(Delimiter::Brace, TokenTree2::from(message).into());
+		[
+			Punct::new(':', Spacing::Joint).into(),
+			Punct::new(':', Spacing::Alone).into(),
+			Ident::new("core", span).into(),
+			Punct::new(':', Spacing::Joint).into(),
+			Punct::new(':', Spacing::Alone).into(),
+			Ident::new("compile_error", span).into(),
+			Punct::new('!', Spacing::Alone).into(),
+			body.into(),
+		]
+		.into_iter()
+		.map(|mut token_tree: TokenTree2| {
+			token_tree.set_span(span);
+			token_tree
+		})
+		.collect()
+	}
+}
+
+fn describe_found(w: &mut dyn Write, found: Option<&TokenTree>) -> fmt::Result {
+	match found {
+		None => Message::new("end-of-input").write(w),
+		Some(TokenTree::Group(group)) => match group.delimiter {
+			Delimiter::Parenthesis => w.write_str("`(`"),
+			Delimiter::Brace => w.write_str("`{`"),
+			Delimiter::Bracket => w.write_str("`[`"),
+			Delimiter::None => Message::new("invisible-group").write(w),
+		},
+		Some(TokenTree::Ident(ident)) => write!(w, "`{ident}`"),
+		Some(TokenTree::Punct(punct)) => write!(w, "`{}`", punct.as_char()),
+		Some(TokenTree::Literal(literal)) => write!(w, "`{literal}`"),
+	}
+}
+
+/// A lazily written [`Parse::describe`].
+#[derive(Clone, Copy)]
+pub struct Expected {
+	describe: fn(&mut dyn Write) -> fmt::Result,
+}
+
+impl Expected {
+	/// Describes through `describe`.
+	pub fn new(describe: fn(&mut dyn Write) -> fmt::Result) -> Self {
+		Self { describe }
+	}
+
+	/// Describes a `T`.
+	pub fn of<'a, T: Parse<'a>>() -> Self {
+		Self::new(T::describe)
+	}
+
+	/// Describes the end of input.
+	#[must_use]
+	pub fn end_of_input() -> Self {
+		Self::new(|w| Message::new("end-of-input").write(w))
+	}
+}
+
+impl Display for Expected {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		(self.describe)(f)
+	}
+}
+
+impl Debug for Expected {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Expected({self})")
+	}
+}
+
+/// A suggested edit of the source text, to fix a [`Diagnostic`].
+#[derive(Debug, Clone)]
+pub struct Suggestion {
+	/// What the edit does.
+	pub message: Message,
+	/// Where the edit goes.
+	pub edit: Edit,
+	/// The text to insert or replace with.
+	pub replacement: String,
+	/// How confident the suggestion is.
+	pub applicability: Applicability,
+}
+
+/// Whether `a` and `b` are known to cover the same source text.
+#[cfg(feature = "span-locations")]
+fn same_location(a: Span, b: Span) -> bool {
+	a.start() == b.start() && a.end() == b.end()
+}
+
+/// Spans can't be told apart without their locations.
+#[cfg(not(feature = "span-locations"))]
+fn same_location(_: Span, _: Span) -> bool {
+	false
+}
+
+/// Where a [`Suggestion`]'s replacement goes.
+#[derive(Debug, Clone, Copy)]
+pub enum Edit {
+	/// Insert the replacement before the span.
+	InsertBefore(Span),
+	/// Insert the replacement after the span.
+	InsertAfter(Span),
+	/// Replace the span.
+	Replace(Span),
+}
+
+/// How confident a [`Suggestion`] is, as in rustc.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Applicability {
+	/// Can be applied automatically.
+	MachineApplicable,
+	/// May not be what the user intended.
+	MaybeIncorrect,
+	/// Contains placeholders that the user has to fill in.
+	HasPlaceholders,
+	/// Not known.
+	Unspecified,
+}
+
+/// A secondary span of a [`Diagnostic`].
+#[derive(Debug, Clone)]
+pub struct Label {
+	/// Where the label points.
+	pub span: Span,
+	/// What the label says.
+	pub message: Message,
+}
+
+/// How severe a [`Diagnostic`] is, as in rustc.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum DiagnosticType {
+	/// The tokens are invalid.
 	Error,
+	/// The tokens are valid, but likely not what was meant.
+	Warning,
+	/// Extra information.
+	Note,
+	/// Advice on how to fix something.
+	Help,
+}
+
+#[cfg(test)]
+mod tests {
+	use proc_macro2::Span;
+
+	use crate::{
+		expressions::{block_expressions::Statements, Expression},
+		parse_str,
+	};
+
+	use super::{Diagnostic, DiagnosticsList};
+
+	#[test]
+	fn errors_become_compile_error_invocations() {
+		let compile_errors = parse_str("a == b == c", |parsing| {
+			parsing
+				.parse::<Expression>()
+				.1
+				.to_compile_errors()
+				.to_string()
+		})
+		.unwrap();
+		assert_eq!(
+			compile_errors,
+			r#":: core :: compile_error ! { "comparison operators cannot be chained\n= note: split the comparison in two and combine them with `&&`" }"#
+		);
+	}
+
+	#[test]
+	fn every_error_is_emitted() {
+		let compile_errors = parse_str("let x: = 1; let y = ;", |parsing| {
+			parsing
+				.parse::<Statements>()
+				.1
+				.to_compile_errors()
+				.to_string()
+		})
+		.unwrap();
+		assert_eq!(
+			compile_errors,
+			":: core :: compile_error ! { \"expected type, found `=`\" } \
+			 :: core :: compile_error ! { \"expected expression, found `;`\" }"
+		);
+	}
+
+	#[test]
+	fn warnings_are_left_out() {
+		let diagnostics = DiagnosticsList::default();
+		diagnostics.push(Diagnostic::warning(Span::call_site(), "unused"));
+		assert!(diagnostics.to_compile_errors().is_empty());
+	}
+
+	#[cfg(feature = "span-locations")]
+	#[test]
+	fn compile_errors_are_spanned_at_the_primary_span() {
+		use proc_macro2::{LineColumn, TokenTree};
+
+		parse_str("a == b == c", |parsing| {
+			for token_tree in parsing.parse::<Expression>().1.to_compile_errors() {
+				assert_eq!(token_tree.span().start(), LineColumn { line: 1, column: 7 });
+				if let TokenTree::Group(group) = token_tree {
+					for token_tree in group.stream() {
+						assert_eq!(token_tree.span().start(), LineColumn { line: 1, column: 7 });
+					}
+				}
+			}
+		})
+		.unwrap();
+	}
 }
@@ -0,0 +1,241 @@
+//! Localizable diagnostic text.
+//!
+//! [`Message`]s are IDs with named arguments, which are formatted through the [installed](`Catalog::install`) [`Catalog`].
+//! English is built in and fills in for any message a catalog lacks.
+//!
+//! Catalogs are text files in a subset of [Fluent](https://projectfluent.org/) syntax:
+//! one `id = pattern` per line, where `{ $name }` is replaced by the argument `name`.
+//! Blank lines and lines starting with `#` are ignored.
+//!
+//! ```ftl
+//! # de.ftl
+//! expected = { $expected } erwartet, { $found } gefunden
+//! end-of-input = Ende der Eingabe
+//! ```
+
+use std::{
+	cell::RefCell,
+	collections::HashMap,
+	fmt::{self, Display, Write},
+	fs, io,
+	path::Path,
+};
+
+/// Diagnostic text to be formatted through a [`Catalog`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Message {
+	/// Which catalog entry to format.
+	pub id: &'static str,
+	/// Named arguments for the entry.
+	pub args: Vec<(&'static str, String)>,
+}
+
+impl Message {
+	/// The message `id`, without arguments so far.
+	#[must_use]
+	pub fn new(id: &'static str) -> Self {
+		Self {
+			id,
+			args: Vec::new(),
+		}
+	}
+
+	/// Adds the argument `name`.
+	#[must_use]
+	pub fn arg(mut self, name: &'static str, value: impl Display) -> Self {
+		self.args.push((name, value.to_string()));
+		self
+	}
+
+	/// Formats the message through the installed catalog, for use in [`Parse::describe`](`crate::io::Parse::describe`).
+	///
+	/// # Errors
+	///
+	/// Iff `w` errors.
+	pub fn write(&self, w: &mut dyn Write) -> fmt::Result {
+		INSTALLED.with(|installed| installed.borrow().write(w, self))
+	}
+}
+
+/// Untranslated text, as is.
+impl From<String> for Message {
+	fn from(text: String) -> Self {
+		Self::new("text").arg("text", text)
+	}
+}
+
+impl From<&str> for Message {
+	fn from(text: &str) -> Self {
+		text.to_string().into()
+	}
+}
+
+impl Display for Message {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.write(f)
+	}
+}
+
+/// Message patterns of one locale.
+#[derive(Debug, Clone, Default)]
+pub struct Catalog {
+	patterns: HashMap<String, String>,
+}
+
+thread_local! {
+	static INSTALLED: RefCell<Catalog> = RefCell::new(Catalog::default());
+}
+
+/// The built-in English patterns.
+const ENGLISH: &[(&str, &str)] = &[
+	("text", "{ $text }"),
+	("expected", "expected { $expected }, found { $found }"),
+	(
+		"expected-one-of",
+		"expected one of { $expected }, found { $found }",
+	),
+	("end-of-input", "end of input"),
+	("invisible-group", "invisible group"),
+	("insert", "insert `{ $insertion }`"),
+	(
+		"chained-comparison",
+		"comparison operators cannot be chained",
+	),
+	(
+		"chained-comparison-note",
+		"split the comparison in two and combine them with `&&`",
+	),
+	("chained-range", "range operators cannot be chained"),
+	(
+		"chained-range-note",
+		"wrap the range that is an operand in parentheses",
+	),
+	("keyword-as-identifier", "`{ $keyword }` is a keyword"),
+	(
+		"escape-keyword",
+		"escape the keyword to use it as an identifier",
+	),
+	(
+		"obsolete-range-pattern",
+		"`...` range patterns are not accepted in Rust { $edition }",
+	),
+	("use-inclusive-range", "use `..=` for an inclusive range"),
+	(
+		"bare-trait-object",
+		"trait objects must include the `dyn` keyword in Rust { $edition }",
+	),
+	("insert-dyn", "add `dyn` before the trait"),
+	("describe-optional", "optionally { $item }"),
+	("describe-any-times", "any times { $item }"),
+	("describe-at-least-once", "at least one time { $item }"),
+	("describe-before", "{ $item } before { $terminators }"),
+	("describe-statement", "statement"),
+	("describe-expression", "expression"),
+	("describe-operator-expression", "operator expression"),
+	("describe-range-expression", "range expression"),
+	("describe-loop-expression", "loop expression"),
+	("describe-infix-operator", "infix operator"),
+	(
+		"describe-arithmetic-or-logical-operator",
+		"([+-*/%&|^]|`<<`|`>>`)",
+	),
+	(
+		"describe-comparison-operator",
+		"(`==`|`!=`|`>`|`<`|`>=`|`<=`)",
+	),
+	("describe-lazy-boolean-operator", "(`&&`|`||`)"),
+	(
+		"describe-compound-assignment-operator",
+		"(`+=`|`-=`|`*=`|`/=`|`%=`|`&=`|`|=`|`^=`|`<<=`|`>>=`)",
+	),
+	("describe-pattern-without-range", "pattern without range"),
+	("describe-type", "type"),
+	("describe-type-no-bounds", "type without bounds"),
+	("describe-literal", "literal"),
+	("describe-identifier", "IDENTIFIER"),
+	("describe-lifetime-or-label", "LIFETIME_OR_LABEL"),
+	("describe-tuple-index", "TUPLE_INDEX"),
+];
+
+impl Catalog {
+	/// Parses a catalog from `source`.
+	///
+	/// # Errors
+	///
+	/// Iff a line is neither blank, a comment nor an `id = pattern` entry.
+	pub fn parse(source: &str) -> io::Result<Self> {
+		let mut patterns = HashMap::new();
+		for (i, line) in source.lines().enumerate() {
+			let line = line.trim();
+			if line.is_empty() || line.starts_with('#') {
+				continue;
+			}
+			let (id, pattern) = line.split_once('=').ok_or_else(|| {
+				io::Error::new(
+					io::ErrorKind::InvalidData,
+					format!("line {}: expected `id = pattern`", i + 1),
+				)
+			})?;
+			patterns.insert(id.trim().to_string(), pattern.trim().to_string());
+		}
+		Ok(Self { patterns })
+	}
+
+	/// Reads and parses the catalog file at `path`.
+	///
+	/// # Errors
+	///
+	/// Iff the file can't be read or isn't a valid catalog.
+	pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+		Self::parse(&fs::read_to_string(path)?)
+	}
+
+	/// Formats diagnostics on the current thread through `self`, returning the previously installed catalog.
+	///
+	/// Install a catalog before parsing, since expectation errors may be formatted right away.
+	#[must_use]
+	pub fn install(self) -> Self {
+		INSTALLED.with(|installed| installed.replace(self))
+	}
+
+	/// Formats `message` through `self`, falling back to English and then to the bare ID.
+	#[must_use]
+	#[allow(clippy::missing_panics_doc)] // Writing into a `String` is infallible.
+	pub fn format(&self, message: &Message) -> String {
+		let mut text = String::new();
+		self.write(&mut text, message).expect("infallible");
+		text
+	}
+
+	fn write(&self, w: &mut dyn Write, message: &Message) -> fmt::Result {
+		let Some(pattern) = self
+			.patterns
+			.get(message.id)
+			.map(String::as_str)
+			.or_else(|| {
+				ENGLISH
+					.iter()
+					.find(|&&(id, _)| id == message.id)
+					.map(|&(_, pattern)| pattern)
+			})
+		else {
+			return w.write_str(message.id);
+		};
+
+		let mut rest = pattern;
+		while let Some(start) = rest.find('{') {
+			let placeable = &rest[start + 1..];
+			let Some(end) = placeable.find('}') else {
+				break;
+			};
+			w.write_str(&rest[..start])?;
+			let name = placeable[..end].trim().trim_start_matches('$');
+			match message.args.iter().find(|&&(arg, _)| arg == name) {
+				Some((_, value)) => w.write_str(value)?,
+				None => w.write_str(&rest[start..start + end + 2])?,
+			}
+			rest = &placeable[end + 1..];
+		}
+		w.write_str(rest)
+	}
+}
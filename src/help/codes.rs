@@ -0,0 +1,247 @@
+//! Stable [`Code`]s of the diagnostics loess emits, with long-form explanations like `rustc --explain`.
+
+use std::fmt::{self, Display};
+
+/// A stable identifier for a kind of [`Diagnostic`](`super::Diagnostic`), to match on instead of its message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Code(&'static str);
+
+impl Code {
+	/// A token that doesn't fit the grammar at its position.
+	pub const UNEXPECTED_TOKEN: Self = Self("L0001");
+	/// A delimited group, like `( … )`, is missing.
+	pub const MISSING_DELIMITER: Self = Self("L0002");
+	/// Tokens are left over after a complete parse.
+	pub const TRAILING_TOKENS: Self = Self("L0003");
+	/// Comparison operators are chained without parentheses.
+	pub const CHAINED_COMPARISON: Self = Self("L0004");
+	/// A keyword is used where an identifier is required.
+	pub const KEYWORD_AS_IDENTIFIER: Self = Self("L0005");
+	/// Syntax that the [`Edition`](`crate::io::edition::Edition`) being parsed doesn't accept.
+	pub const EDITION_GATED: Self = Self("L0006");
+	/// Range operators are chained without parentheses.
+	pub const CHAINED_RANGE: Self = Self("L0007");
+
+	/// The code with the identifier `code`, like `"L0001"`, if there is one.
+	#[must_use]
+	pub fn find(code: &str) -> Option<Self> {
+		REGISTRY
+			.iter()
+			.map(|&(known, _)| known)
+			.find(|known| known.0 == code)
+	}
+
+	/// The code's identifier, like `"L0001"`.
+	#[must_use]
+	pub fn as_str(self) -> &'static str {
+		self.0
+	}
+
+	/// The long-form explanation of the code, in Markdown, with an erroneous and a fixed example.
+	///
+	/// # Panics
+	///
+	/// Iff `self` isn't listed in [`REGISTRY`].
+	#[must_use]
+	pub fn explanation(self) -> &'static str {
+		REGISTRY
+			.iter()
+			.find(|&&(known, _)| known == self)
+			.map(|&(_, explanation)| explanation)
+			.expect("registered")
+	}
+}
+
+impl Display for Code {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.0)
+	}
+}
+
+/// All codes with their explanations, in order.
+pub const REGISTRY: &[(Code, &str)] = &[
+	(
+		Code::UNEXPECTED_TOKEN,
+		r"A token doesn't fit the grammar at its position.
+
+The parser expected one of the listed kinds of tokens or syntax there,
+but found something else or reached the end of input.
+
+Erroneous code example:
+
+```compile_fail
+let x = ;
+```
+
+Complete the syntax that's cut short, or remove the stray token:
+
+```
+let x = 1;
+```
+",
+	),
+	(
+		Code::MISSING_DELIMITER,
+		r"A delimited group is missing.
+
+Some syntax requires its contents in parentheses, brackets or braces,
+like the body of a function or the arguments of a call.
+
+Erroneous code example:
+
+```compile_fail
+fn main() println!();
+```
+
+Enclose the contents in the expected delimiters:
+
+```
+fn main() { println!(); }
+```
+",
+	),
+	(
+		Code::TRAILING_TOKENS,
+		r"Tokens are left over after what was parsed.
+
+The input was parsed completely up to a point, but the parsed syntax can't continue with what follows.
+
+Erroneous code example:
+
+```compile_fail
+let x = 1 2;
+```
+
+Remove the extra tokens, or connect them to the rest with an operator or separator:
+
+```
+let x = 1 + 2;
+```
+",
+	),
+	(
+		Code::CHAINED_COMPARISON,
+		r"Comparison operators are chained.
+
+Comparison operators are non-associative, so `a < b < c` is neither `(a < b) < c` nor `a < (b < c)`.
+
+Erroneous code example:
+
+```compile_fail
+if 1 < x < 10 {}
+```
+
+Split the comparison in two and combine them with `&&`:
+
+```
+if 1 < x && x < 10 {}
+```
+",
+	),
+	(
+		Code::KEYWORD_AS_IDENTIFIER,
+		r"A keyword is used where an identifier is required.
+
+Strict and reserved keywords aren't identifiers, but most can be used as one if written as raw identifier.
+`crate`, `self`, `Self` and `super` can't be raw identifiers.
+
+Erroneous code example:
+
+```compile_fail
+let type = 1;
+```
+
+Prefix the keyword with `r#`, or rename it:
+
+```
+let r#type = 1;
+```
+",
+	),
+	(
+		Code::EDITION_GATED,
+		r"Syntax isn't accepted in the edition being parsed.
+
+Editions change some syntax. For example, `...` range patterns were removed in Rust 2021,
+and trait objects require `dyn` since then.
+
+Erroneous code example (Rust 2021):
+
+```compile_fail,edition2021
+match 5 {
+    0...9 => {}
+    _ => {}
+}
+```
+
+Use the syntax of the current edition instead:
+
+```
+match 5 {
+    0..=9 => {}
+    _ => {}
+}
+```
+",
+	),
+	(
+		Code::CHAINED_RANGE,
+		r"Range operators are chained.
+
+Range operators are non-associative, so `a..b..c` is neither `(a..b)..c` nor `a..(b..c)`.
+
+Erroneous code example:
+
+```compile_fail
+let ranges = 0..1..2;
+```
+
+Wrap the range that is an operand in parentheses:
+
+```
+let ranges = (0..1)..(1..2);
+```
+",
+	),
+];
+
+#[cfg(test)]
+mod tests {
+	use super::{Code, REGISTRY};
+
+	#[test]
+	fn find_registered_codes() {
+		for (code, id) in [
+			(Code::UNEXPECTED_TOKEN, "L0001"),
+			(Code::MISSING_DELIMITER, "L0002"),
+			(Code::TRAILING_TOKENS, "L0003"),
+			(Code::CHAINED_COMPARISON, "L0004"),
+			(Code::KEYWORD_AS_IDENTIFIER, "L0005"),
+			(Code::EDITION_GATED, "L0006"),
+			(Code::CHAINED_RANGE, "L0007"),
+		] {
+			assert_eq!(Code::find(id), Some(code));
+			assert_eq!(code.as_str(), id);
+			assert_eq!(code.to_string(), id);
+		}
+	}
+
+	#[test]
+	fn find_unknown_codes() {
+		assert_eq!(Code::find("L0000"), None);
+		assert_eq!(Code::find("l0001"), None);
+		assert_eq!(Code::find("E0001"), None);
+		assert_eq!(Code::find(""), None);
+	}
+
+	#[test]
+	fn registry_is_ordered_and_explained() {
+		for pair in REGISTRY.windows(2) {
+			assert!(pair[0].0 < pair[1].0, "{} before {}", pair[0].0, pair[1].0);
+		}
+		for &(code, explanation) in REGISTRY {
+			assert!(explanation.contains("```compile_fail"), "{code}");
+			assert_eq!(code.explanation(), explanation);
+		}
+	}
+}
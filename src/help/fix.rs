@@ -0,0 +1,87 @@
+//! Applying [`Suggestion`]s to the source text, to fix diagnostics automatically.
+//!
+//! Like [`render`](`super::render`), this requires the `span-locations` feature.
+
+use super::{render::SourceText, Applicability, DiagnosticsList};
+
+impl SourceText {
+	/// The text with all [machine-applicable](`Applicability::MachineApplicable`) suggestions in `diagnostics` applied.
+	///
+	/// Suggestions that overlap an earlier one are skipped, so fixing may take multiple rounds.
+	pub fn apply_suggestions(&self, diagnostics: &DiagnosticsList<'_>) -> String {
+		let mut edits: Vec<((usize, usize), &str)> = Vec::new();
+		let diagnostics = diagnostics.diagnostics.borrow();
+		for suggestion in diagnostics
+			.iter()
+			.flat_map(|diagnostic| &diagnostic.suggestions)
+		{
+			if suggestion.applicability == Applicability::MachineApplicable {
+				if let Some(range) = self.edit_range(suggestion.edit) {
+					edits.push(((range.start.byte, range.end.byte), &suggestion.replacement));
+				}
+			}
+		}
+		edits.sort_by_key(|&((start, end), _)| (start, end));
+
+		let mut fixed = String::with_capacity(self.text.len());
+		let mut copied = 0;
+		for ((start, end), replacement) in edits {
+			if start < copied {
+				continue;
+			}
+			fixed.push_str(&self.text[copied..start]);
+			fixed.push_str(replacement);
+			copied = end;
+		}
+		fixed.push_str(&self.text[copied..]);
+		fixed
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{
+		expressions::block_expressions::Statements,
+		help::{render::SourceText, Applicability},
+		parse_str,
+	};
+
+	/// `source` with the fixes for its errors as the contents of a block applied, and their applicabilities.
+	fn fixed(source: &str) -> (String, Vec<Applicability>) {
+		let text = SourceText::new("lib.rs", source);
+		parse_str(source, |parsing| {
+			let (_, diagnostics): (Statements, _) = parsing.parse();
+			let applicabilities = diagnostics
+				.diagnostics
+				.borrow()
+				.iter()
+				.flat_map(|diagnostic| &diagnostic.suggestions)
+				.map(|suggestion| suggestion.applicability)
+				.collect();
+			(text.apply_suggestions(&diagnostics), applicabilities)
+		})
+		.unwrap()
+	}
+
+	#[test]
+	fn missing_semicolon_is_inserted() {
+		assert_eq!(
+			fixed("let x = 1 let y = 2;"),
+			(
+				"let x = 1; let y = 2;".to_owned(),
+				vec![Applicability::MachineApplicable]
+			)
+		);
+	}
+
+	#[test]
+	fn conflicting_fixes_are_not_applied() {
+		let (fixed, applicabilities) = fixed("foo() bar();");
+		assert_eq!(fixed, "foo() bar();");
+		assert!(
+			applicabilities.len() <= 1
+				&& !applicabilities.contains(&Applicability::MachineApplicable),
+			"{applicabilities:?}"
+		);
+	}
+}
@@ -0,0 +1,313 @@
+//! Machine-readable [`Diagnostic`]s, as rustc's `--error-format=json` and as LSP `Diagnostic` objects.
+//!
+//! Like [`render`](`super::render`), this requires the `span-locations` feature.
+
+use std::fmt::{self, Write};
+
+use super::{
+	render::{Colors, Range, SourceText},
+	Diagnostic, DiagnosticType, DiagnosticsList, Suggestion,
+};
+
+impl SourceText {
+	/// One rustc-compatible JSON object per diagnostic, each on its own line.
+	#[must_use]
+	#[allow(clippy::missing_panics_doc)] // Writing into a `String` is infallible.
+	pub fn to_rustc_json(&self, diagnostics: &DiagnosticsList<'_>) -> String {
+		let mut json = String::new();
+		for diagnostic in diagnostics.diagnostics.borrow().iter() {
+			self.write_rustc_json(&mut json, diagnostic)
+				.expect("infallible");
+			json.push('\n');
+		}
+		json
+	}
+
+	/// A JSON array of LSP `Diagnostic` objects, with related information located in the document at `uri`.
+	#[must_use]
+	#[allow(clippy::missing_panics_doc)] // Writing into a `String` is infallible.
+	pub fn to_lsp_json(&self, diagnostics: &DiagnosticsList<'_>, uri: &str) -> String {
+		let mut json = String::from("[");
+		for (i, diagnostic) in diagnostics.diagnostics.borrow().iter().enumerate() {
+			if i > 0 {
+				json.push(',');
+			}
+			self.write_lsp_json(&mut json, diagnostic, uri)
+				.expect("infallible");
+		}
+		json.push(']');
+		json
+	}
+
+	fn write_rustc_json(&self, w: &mut dyn Write, diagnostic: &Diagnostic<'_>) -> fmt::Result {
+		let mut rendered = String::new();
+		self.render(&mut rendered, diagnostic, Colors::Never)?;
+
+		w.write_str(r#"{"$message_type":"diagnostic","message":"#)?;
+		write_string(w, &diagnostic.clone().rendered().message.to_string())?;
+		w.write_str(r#","code":"#)?;
+		match diagnostic.code {
+			Some(code) => {
+				w.write_str(r#"{"code":"#)?;
+				write_string(w, code.as_str())?;
+				w.write_str(r#","explanation":"#)?;
+				write_string(w, code.explanation())?;
+				w.write_char('}')?;
+			}
+			None => w.write_str("null")?,
+		}
+		w.write_str(r#","level":"#)?;
+		write_string(w, diagnostic.r#type.name())?;
+		w.write_str(r#","spans":["#)?;
+		let spans = Some((diagnostic.span, true, None))
+			.into_iter()
+			.chain(
+				diagnostic
+					.labels
+					.iter()
+					.map(|label| (label.span, false, Some(label.message.to_string()))),
+			)
+			.filter_map(|(span, is_primary, label)| Some((self.range(span)?, is_primary, label)));
+		for (i, (range, is_primary, label)) in spans.enumerate() {
+			if i > 0 {
+				w.write_char(',')?;
+			}
+			self.write_rustc_span(w, &range, is_primary, label.as_deref(), None)?;
+		}
+		w.write_str(r#"],"children":["#)?;
+		for (i, note) in diagnostic.notes.iter().enumerate() {
+			if i > 0 {
+				w.write_char(',')?;
+			}
+			w.write_str(r#"{"message":"#)?;
+			write_string(w, &note.to_string())?;
+			w.write_str(
+				r#","code":null,"level":"note","spans":[],"children":[],"rendered":null}"#,
+			)?;
+		}
+		for (i, suggestion) in diagnostic.suggestions.iter().enumerate() {
+			if i > 0 || !diagnostic.notes.is_empty() {
+				w.write_char(',')?;
+			}
+			w.write_str(r#"{"message":"#)?;
+			write_string(w, &suggestion.message.to_string())?;
+			w.write_str(r#","code":null,"level":"help","spans":["#)?;
+			if let Some(range) = self.edit_range(suggestion.edit) {
+				self.write_rustc_span(w, &range, true, None, Some(suggestion))?;
+			}
+			w.write_str(r#"],"children":[],"rendered":null}"#)?;
+		}
+		w.write_str(r#"],"rendered":"#)?;
+		write_string(w, &rendered)?;
+		w.write_char('}')
+	}
+
+	fn write_rustc_span(
+		&self,
+		w: &mut dyn Write,
+		range: &Range,
+		is_primary: bool,
+		label: Option<&str>,
+		suggestion: Option<&Suggestion>,
+	) -> fmt::Result {
+		let Range { start, end } = range;
+		w.write_str(r#"{"file_name":"#)?;
+		write_string(w, &self.name)?;
+		write!(
+			w,
+			r#","byte_start":{},"byte_end":{},"line_start":{},"line_end":{},"column_start":{},"column_end":{},"is_primary":{is_primary},"text":["#,
+			start.byte,
+			end.byte,
+			start.line,
+			end.line,
+			start.column + 1,
+			end.column + 1,
+		)?;
+		for line in start.line..=end.line {
+			if line > start.line {
+				w.write_char(',')?;
+			}
+			let text = self.line(line);
+			let highlight_start = if line == start.line { start.column } else { 0 };
+			let highlight_end = if line == end.line {
+				end.column
+			} else {
+				text.chars().count()
+			};
+			w.write_str(r#"{"text":"#)?;
+			write_string(w, text)?;
+			write!(
+				w,
+				r#","highlight_start":{},"highlight_end":{}}}"#,
+				highlight_start + 1,
+				highlight_end + 1,
+			)?;
+		}
+		w.write_str(r#"],"label":"#)?;
+		match label {
+			Some(label) => write_string(w, label)?,
+			None => w.write_str("null")?,
+		}
+		match suggestion {
+			Some(suggestion) => {
+				w.write_str(r#","suggested_replacement":"#)?;
+				write_string(w, &suggestion.replacement)?;
+				w.write_str(r#","suggestion_applicability":"#)?;
+				write_string(w, &format!("{:?}", suggestion.applicability))?;
+			}
+			None => {
+				w.write_str(r#","suggested_replacement":null,"suggestion_applicability":null"#)?
+			}
+		}
+		w.write_str(r#","expansion":null}"#)
+	}
+
+	fn write_lsp_json(
+		&self,
+		w: &mut dyn Write,
+		diagnostic: &Diagnostic<'_>,
+		uri: &str,
+	) -> fmt::Result {
+		let mut message = diagnostic.clone().rendered().message.to_string();
+		for note in &diagnostic.notes {
+			write!(message, "\nnote: {note}")?;
+		}
+
+		w.write_str(r#"{"range":"#)?;
+		match self.range(diagnostic.span) {
+			Some(range) => write_lsp_range(w, &range)?,
+			None => {
+				w.write_str(r#"{"start":{"line":0,"character":0},"end":{"line":0,"character":0}}"#)?
+			}
+		}
+		write!(w, r#","severity":{},"#, lsp_severity(diagnostic.r#type))?;
+		if let Some(code) = diagnostic.code {
+			w.write_str(r#""code":"#)?;
+			write_string(w, code.as_str())?;
+			w.write_char(',')?;
+		}
+		w.write_str(r#""source":"loess","message":"#)?;
+		write_string(w, &message)?;
+		w.write_str(r#","relatedInformation":["#)?;
+		let related = diagnostic
+			.labels
+			.iter()
+			.filter_map(|label| Some((self.range(label.span)?, &label.message)));
+		for (i, (range, message)) in related.enumerate() {
+			if i > 0 {
+				w.write_char(',')?;
+			}
+			w.write_str(r#"{"location":{"uri":"#)?;
+			write_string(w, uri)?;
+			w.write_str(r#","range":"#)?;
+			write_lsp_range(w, &range)?;
+			w.write_str(r#"},"message":"#)?;
+			write_string(w, &message.to_string())?;
+			w.write_char('}')?;
+		}
+		w.write_str("]}")
+	}
+}
+
+fn write_lsp_range(w: &mut dyn Write, Range { start, end }: &Range) -> fmt::Result {
+	// LSP lines are 0-based.
+	write!(
+		w,
+		r#"{{"start":{{"line":{},"character":{}}},"end":{{"line":{},"character":{}}}}}"#,
+		start.line - 1,
+		start.utf16_column,
+		end.line - 1,
+		end.utf16_column,
+	)
+}
+
+/// `DiagnosticSeverity` as in the LSP specification.
+fn lsp_severity(r#type: DiagnosticType) -> u8 {
+	match r#type {
+		DiagnosticType::Error => 1,
+		DiagnosticType::Warning => 2,
+		DiagnosticType::Note => 3,
+		DiagnosticType::Help => 4,
+	}
+}
+
+/// Writes `s` as JSON string literal.
+fn write_string(w: &mut dyn Write, s: &str) -> fmt::Result {
+	w.write_char('"')?;
+	for ch in s.chars() {
+		match ch {
+			'"' => w.write_str(r#"\""#)?,
+			'\\' => w.write_str(r"\\")?,
+			'\n' => w.write_str(r"\n")?,
+			'\r' => w.write_str(r"\r")?,
+			'\t' => w.write_str(r"\t")?,
+			ch if ch < ' ' => write!(w, r"\u{:04x}", u32::from(ch))?,
+			ch => w.write_char(ch)?,
+		}
+	}
+	w.write_char('"')
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{
+		expressions::Expression, help::DiagnosticsList, parse_str, patterns::Pattern, Parsing,
+	};
+
+	use super::{super::render::SourceText, write_string};
+
+	fn rustc_json(
+		source: &str,
+		parse: impl for<'a> FnOnce(Parsing<'a>) -> DiagnosticsList<'a>,
+	) -> String {
+		let text = SourceText::new("lib.rs", source);
+		parse_str(source, |parsing| text.to_rustc_json(&parse(parsing))).unwrap()
+	}
+
+	#[test]
+	fn rustc_json_shape() {
+		let json = rustc_json("a == b == c", |parsing| parsing.parse::<Expression>().1);
+		assert_eq!(json.lines().count(), 1);
+		assert!(json.ends_with("}\n"));
+		for fragment in [
+			r#"{"$message_type":"diagnostic","message":"comparison operators cannot be chained","code":{"code":"L0004","explanation":"Comparison operators are chained."#,
+			r#""level":"error","spans":[{"file_name":"lib.rs","byte_start":7,"byte_end":9,"line_start":1,"line_end":1,"column_start":8,"column_end":10,"is_primary":true,"text":[{"text":"a == b == c","highlight_start":8,"highlight_end":10}],"label":null,"suggested_replacement":null,"suggestion_applicability":null,"expansion":null}]"#,
+			r#""children":[{"message":"split the comparison in two and combine them with `&&`","code":null,"level":"note","spans":[],"children":[],"rendered":null}]"#,
+			r#""rendered":"error[L0004]: comparison operators cannot be chained\n --> lib.rs:1:8\n"#,
+		] {
+			assert!(json.contains(fragment), "{fragment}\nnot in\n{json}");
+		}
+	}
+
+	#[test]
+	fn rustc_json_suggestions() {
+		let json = rustc_json("0...9", |parsing| parsing.parse::<Pattern>().1);
+		assert!(
+			json.contains(
+				r#""level":"help","spans":[{"file_name":"lib.rs","byte_start":1,"byte_end":4,"#
+			),
+			"{json}"
+		);
+		assert!(
+			json.contains(
+				r#""suggested_replacement":"..=","suggestion_applicability":"MachineApplicable""#
+			),
+			"{json}"
+		);
+	}
+
+	#[test]
+	fn rustc_json_without_diagnostics() {
+		assert_eq!(
+			rustc_json("1 + 2", |parsing| parsing.parse::<Expression>().1),
+			""
+		);
+	}
+
+	#[test]
+	fn string_escapes() {
+		let mut json = String::new();
+		write_string(&mut json, "\"\\\n\t\u{1}é").unwrap();
+		assert_eq!(json, r#""\"\\\n\t\u0001é""#);
+	}
+}
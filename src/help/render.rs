@@ -0,0 +1,405 @@
+//! Rustc-style rendering of [`Diagnostic`]s against the source text they were parsed from.
+//!
+//! Requires the `span-locations` feature, without which spans don't know their lines and columns.
+
+use std::fmt::{self, Write};
+
+use proc_macro2::{LineColumn, Span};
+
+use super::{Diagnostic, DiagnosticType, DiagnosticsList, Edit};
+
+/// Parsed source text along with its name, for rendering diagnostics.
+#[derive(Debug, Clone)]
+pub struct SourceText {
+	/// The file name or other label shown in locations.
+	pub name: String,
+	/// The source text the spans point into.
+	pub text: String,
+}
+
+/// ANSI colouring of rendered diagnostics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Colors {
+	/// Plain text.
+	#[default]
+	Never,
+	/// ANSI escape codes.
+	Always,
+}
+
+const RESET: &str = "\x1b[0m";
+const BOLD: &str = "\x1b[1m";
+const GUTTER: &str = "\x1b[1;34m";
+
+const TAB_WIDTH: usize = 4;
+
+impl DiagnosticType {
+	pub(super) fn name(self) -> &'static str {
+		match self {
+			Self::Error => "error",
+			Self::Warning => "warning",
+			Self::Note => "note",
+			Self::Help => "help",
+		}
+	}
+
+	fn color(self) -> &'static str {
+		match self {
+			Self::Error => "\x1b[1;31m",
+			Self::Warning => "\x1b[1;33m",
+			Self::Note => "\x1b[1;32m",
+			Self::Help => "\x1b[1;36m",
+		}
+	}
+}
+
+/// A location in a [`SourceText`], in all the units the formats need.
+#[derive(Clone, Copy)]
+pub(super) struct Position {
+	pub byte: usize,
+	/// 1-based.
+	pub line: usize,
+	/// 0-based, in `char`s.
+	pub column: usize,
+	/// 0-based, in UTF-16 code units.
+	pub utf16_column: usize,
+}
+
+/// A located span.
+pub(super) struct Range {
+	pub start: Position,
+	pub end: Position,
+}
+
+/// An underline below a source line.
+struct Annotation {
+	line: usize,
+	/// Display columns, end exclusive.
+	columns: (usize, usize),
+	primary: bool,
+	label: Option<String>,
+}
+
+impl SourceText {
+	/// Source text `text` labelled `name`.
+	#[must_use]
+	pub fn new(name: impl Into<String>, text: impl Into<String>) -> Self {
+		Self {
+			name: name.into(),
+			text: text.into(),
+		}
+	}
+
+	/// Renders all of `diagnostics`, separated by blank lines.
+	///
+	/// # Errors
+	///
+	/// Iff writing to `w` fails.
+	pub fn render_all(
+		&self,
+		w: &mut dyn Write,
+		diagnostics: &DiagnosticsList<'_>,
+		colors: Colors,
+	) -> fmt::Result {
+		for diagnostic in diagnostics.diagnostics.borrow().iter() {
+			self.render(w, diagnostic, colors)?;
+			w.write_char('\n')?;
+		}
+		Ok(())
+	}
+
+	/// Renders `diagnostic` like rustc does, with a snippet of the lines its spans are on.
+	///
+	/// # Errors
+	///
+	/// Iff writing to `w` fails.
+	pub fn render(
+		&self,
+		w: &mut dyn Write,
+		diagnostic: &Diagnostic<'_>,
+		colors: Colors,
+	) -> fmt::Result {
+		let paint = |style: &'static str| match colors {
+			Colors::Never => "",
+			Colors::Always => style,
+		};
+		let reset = paint(RESET);
+
+		let message = diagnostic.clone().rendered().message;
+		let code = diagnostic
+			.code
+			.map(|code| format!("[{code}]"))
+			.unwrap_or_default();
+		writeln!(
+			w,
+			"{}{}{code}{reset}{}: {message}{reset}",
+			paint(diagnostic.r#type.color()),
+			diagnostic.r#type.name(),
+			paint(BOLD),
+		)?;
+
+		let mut annotations: Vec<Annotation> = self
+			.annotation(diagnostic.span, true, None)
+			.into_iter()
+			.chain(diagnostic.labels.iter().filter_map(|label| {
+				self.annotation(label.span, false, Some(label.message.to_string()))
+			}))
+			.collect();
+		annotations.sort_by_key(|annotation| (annotation.line, !annotation.primary));
+
+		let width = annotations
+			.iter()
+			.map(|annotation| annotation.line.to_string().len())
+			.max()
+			.unwrap_or(0);
+		let gutter = paint(GUTTER);
+		let pad = " ".repeat(width);
+
+		if annotations.iter().any(|annotation| annotation.primary) {
+			let LineColumn { line, column } = diagnostic.span.start();
+			writeln!(
+				w,
+				"{pad}{gutter}-->{reset} {}:{line}:{}",
+				self.name,
+				column + 1
+			)?;
+			writeln!(w, "{pad} {gutter}|{reset}")?;
+		}
+
+		let mut annotations = annotations.iter().peekable();
+		while let Some(first) = annotations.next() {
+			let line = first.line;
+			writeln!(
+				w,
+				"{gutter}{line:>width$} |{reset} {}",
+				expand_tabs(self.line(line)).trim_end()
+			)?;
+			let mut on_line = vec![first];
+			while let Some(next) = annotations.next_if(|next| next.line == line) {
+				on_line.push(next);
+			}
+			for annotation in on_line {
+				let (start, end) = annotation.columns;
+				let (marker, style) = if annotation.primary {
+					('^', diagnostic.r#type.color())
+				} else {
+					('-', GUTTER)
+				};
+				write!(
+					w,
+					"{pad} {gutter}|{reset} {}{}{}",
+					" ".repeat(start),
+					paint(style),
+					marker.to_string().repeat(end - start),
+				)?;
+				if let Some(label) = &annotation.label {
+					write!(w, " {label}")?;
+				}
+				writeln!(w, "{reset}")?;
+			}
+		}
+
+		for note in &diagnostic.notes {
+			writeln!(
+				w,
+				"{pad} {gutter}={reset} {}note{reset}: {note}",
+				paint(BOLD)
+			)?;
+		}
+		for suggestion in &diagnostic.suggestions {
+			writeln!(
+				w,
+				"{pad} {gutter}={reset} {}help{reset}: {}",
+				paint(BOLD),
+				suggestion.message
+			)?;
+		}
+		Ok(())
+	}
+
+	/// Underlines `span` on its first line, if it has a location.
+	fn annotation(&self, span: Span, primary: bool, label: Option<String>) -> Option<Annotation> {
+		let (start, end) = (span.start(), span.end());
+		if start.line == 0 {
+			// No location information, as in procedural macros on stable Rust.
+			return None;
+		}
+
+		let line = self.line(start.line);
+		let start_column = display_column(line, start.column);
+		let end_column = if end.line == start.line {
+			display_column(line, end.column)
+		} else {
+			display_column(line, line.chars().count())
+		};
+		Some(Annotation {
+			line: start.line,
+			columns: (start_column, end_column.max(start_column + 1)),
+			primary,
+			label,
+		})
+	}
+
+	/// Locates `span`, if it has a location.
+	pub(super) fn range(&self, span: Span) -> Option<Range> {
+		let (start, end) = (span.start(), span.end());
+		if start.line == 0 {
+			return None;
+		}
+		Some(Range {
+			start: self.position(start),
+			end: self.position(end),
+		})
+	}
+
+	/// Locates the text `edit` replaces, if its span has a location.
+	pub(super) fn edit_range(&self, edit: Edit) -> Option<Range> {
+		match edit {
+			Edit::InsertBefore(span) => {
+				let start = self.range(span)?.start;
+				Some(Range { start, end: start })
+			}
+			Edit::InsertAfter(span) => {
+				let end = self.range(span)?.end;
+				Some(Range { start: end, end })
+			}
+			Edit::Replace(span) => self.range(span),
+		}
+	}
+
+	fn position(&self, LineColumn { line, column }: LineColumn) -> Position {
+		let line_start: usize = self
+			.text
+			.split_inclusive('\n')
+			.take(line - 1)
+			.map(str::len)
+			.sum();
+		let prefix = self.line(line).chars().take(column);
+		let (bytes, utf16_column) = prefix.fold((0, 0), |(bytes, utf16), ch| {
+			(bytes + ch.len_utf8(), utf16 + ch.len_utf16())
+		});
+		Position {
+			byte: line_start + bytes,
+			line,
+			column,
+			utf16_column,
+		}
+	}
+
+	/// The 1-based `line`, or an empty one past the end.
+	pub(super) fn line(&self, line: usize) -> &str {
+		self.text.lines().nth(line - 1).unwrap_or("")
+	}
+}
+
+fn expand_tabs(line: &str) -> String {
+	line.replace('\t', &" ".repeat(TAB_WIDTH))
+}
+
+/// The display column of the character at `column` in `line`, with tabs expanded.
+fn display_column(line: &str, column: usize) -> usize {
+	line.chars()
+		.take(column)
+		.map(|ch| if ch == '\t' { TAB_WIDTH } else { 1 })
+		.sum::<usize>()
+		+ column.saturating_sub(line.chars().count())
+}
+
+#[cfg(test)]
+mod tests {
+	use proc_macro2::TokenStream;
+
+	use crate::{
+		expressions::{block_expressions::Statements, Expression},
+		help::{Diagnostic, DiagnosticsList},
+		parse_str, Parsing,
+	};
+
+	use super::{Colors, SourceText};
+
+	fn rendered(
+		source: &str,
+		colors: Colors,
+		parse: impl for<'a> FnOnce(Parsing<'a>) -> DiagnosticsList<'a>,
+	) -> String {
+		let text = SourceText::new("lib.rs", source);
+		parse_str(source, |parsing| {
+			let mut rendered = String::new();
+			text.render_all(&mut rendered, &parse(parsing), colors)
+				.unwrap();
+			rendered
+		})
+		.unwrap()
+	}
+
+	#[test]
+	fn rustc_style() {
+		assert_eq!(
+			rendered("a == b == c", Colors::Never, |parsing| {
+				parsing.parse::<Expression>().1
+			}),
+			"\
+error[L0004]: comparison operators cannot be chained
+ --> lib.rs:1:8
+  |
+1 | a == b == c
+  |        ^^
+  = note: split the comparison in two and combine them with `&&`
+
+"
+		);
+	}
+
+	#[test]
+	fn tabs_are_expanded() {
+		assert_eq!(
+			rendered("let x = 1;\n\tlet y = ;", Colors::Never, |parsing| {
+				parsing.parse::<Statements>().1
+			}),
+			"\
+error[L0001]: expected expression, found `;`
+ --> lib.rs:2:10
+  |
+2 |     let y = ;
+  |             ^
+
+"
+		);
+	}
+
+	#[test]
+	fn labels_are_secondary() {
+		let source = "let x = 1;";
+		let tokens: Vec<_> = source.parse::<TokenStream>().unwrap().into_iter().collect();
+		let diagnostic = Diagnostic::error(tokens[1].span(), "unused variable")
+			.with_label(tokens[3].span(), "assigned here");
+
+		let mut rendered = String::new();
+		SourceText::new("lib.rs", source)
+			.render(&mut rendered, &diagnostic, Colors::Never)
+			.unwrap();
+		assert_eq!(
+			rendered,
+			"\
+error: unused variable
+ --> lib.rs:1:5
+  |
+1 | let x = 1;
+  |     ^
+  |         - assigned here
+"
+		);
+	}
+
+	#[test]
+	fn colors_are_optional() {
+		fn parse(parsing: Parsing<'_>) -> DiagnosticsList<'_> {
+			parsing.parse::<Expression>().1
+		}
+
+		assert!(!rendered("a == b == c", Colors::Never, parse).contains('\x1b'));
+		assert!(rendered("a == b == c", Colors::Always, parse).starts_with(
+			"\x1b[1;31merror[L0004]\x1b[0m\x1b[1m: comparison operators cannot be chained"
+		));
+	}
+}
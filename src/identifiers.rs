@@ -1,25 +1,107 @@
-use proc_macro2::{Ident, Span};
+//! Identifiers, which exclude keywords unless raw.
 
-use crate::io::{Input, Parse};
+use proc_macro2::Ident;
 
+use crate::{
+	help::{catalog::Message, codes::Code, Applicability, Diagnostic, Edit, Suggestion},
+	io::{default_span, first::First, random_access::TokenTree, Input, Parse},
+	tokens::keywords,
+};
+
+/// Keywords that can't be raw identifiers either.
+const NOT_RAW: &[&str] = &["crate", "self", "Self", "super"];
+
+/// A non-keyword or raw identifier.
+#[derive(Debug, Clone)]
 pub struct Identifier {
+	/// Without the `r#` of raw identifiers.
 	pub ident: Ident,
+	/// Whether the identifier was written as raw identifier, like `r#type`.
+	pub raw: bool,
 }
 
 impl Parse<'_> for Identifier {
 	fn parse(input: &mut Input<'_>) -> Self {
-		todo!("except keywords and reserved words")
+		let Some(TokenTree::Ident(ident)) = input.cursor.first().filter(|token_tree| {
+			// `_` is lexed as identifier, but isn't one.
+			!matches!(token_tree, TokenTree::Ident(ident) if ident == "_")
+		}) else {
+			return input.error_expected();
+		};
+
+		let name = ident.to_string();
+		let raw = name.starts_with("r#");
+		if !raw && keywords::is_keyword(&name, input.edition) {
+			let mut diagnostic = Diagnostic::expected::<Self>(input)
+				.with_code(Code::KEYWORD_AS_IDENTIFIER)
+				.with_note(Message::new("keyword-as-identifier").arg("keyword", &name));
+			if !NOT_RAW.contains(&name.as_str()) {
+				diagnostic = diagnostic.with_suggestion(Suggestion {
+					message: Message::new("escape-keyword"),
+					edit: Edit::Replace(ident.span()),
+					replacement: format!("r#{name}"),
+					applicability: Applicability::MachineApplicable,
+				});
+			}
+			input.diagnostics.push(diagnostic);
+		}
+
+		// A keyword is taken as identifier anyway, since that's most likely what was meant.
+		input.consume(1);
+		Self {
+			ident: ident.clone(),
+			raw,
+		}
+	}
+
+	fn describe(w: &mut dyn std::fmt::Write) -> std::fmt::Result {
+		Message::new("describe-identifier").write(w)
 	}
 
-	fn describe(w: &mut dyn std::fmt::Write) {
-		w.write_str("IDENTIFIER")
+	fn first() -> First {
+		First::ident()
 	}
 }
 
 impl Default for Identifier {
 	fn default() -> Self {
 		Self {
-			ident: Ident::new("MISSING", Span::mixed_site()),
+			ident: Ident::new("MISSING", default_span()),
+			raw: false,
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use crate::{help::codes::Code, io::edition::Edition, parse2_in};
+
+	use super::Identifier;
+
+	fn codes(source: &str, edition: Edition) -> Vec<Option<Code>> {
+		parse2_in(source.parse().unwrap(), edition, |parsing| {
+			let (_, diagnostics): (Identifier, _) = parsing.parse();
+			diagnostics.codes()
+		})
+	}
+
+	#[test]
+	fn keywords_depend_on_the_edition() {
+		assert_eq!(codes("async", Edition::Edition2015), []);
+		assert_eq!(
+			codes("async", Edition::Edition2018),
+			[Some(Code::KEYWORD_AS_IDENTIFIER)]
+		);
+		assert_eq!(codes("gen", Edition::Edition2021), []);
+		assert_eq!(
+			codes("gen", Edition::Edition2024),
+			[Some(Code::KEYWORD_AS_IDENTIFIER)]
+		);
+	}
+
+	#[test]
+	fn raw_keywords_are_identifiers() {
+		assert_eq!(codes("r#async", Edition::Edition2018), []);
+		assert_eq!(codes("r#gen", Edition::Edition2024), []);
+	}
+}
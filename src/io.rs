@@ -1,39 +1,288 @@
-use std::fmt::Write;
+//! Parser input and the [`Parse`] trait.
+
+use std::{
+	cell::Cell,
+	fmt::{self, Write},
+	mem,
+};
 
 use either::Either;
+use proc_macro2::{extra::DelimSpan, Delimiter, Span};
 use this_is_fine::{Fine, FineExt};
 use vec1::Vec1;
 
-use crate::help::DiagnosticsList;
+use crate::{
+	help::{
+		catalog::Message, codes::Code, Applicability, Diagnostic, DiagnosticsList, Edit, Expected,
+		Suggestion,
+	},
+	tokens::{glued_len, spells},
+};
 
-use self::random_access::TokenTree;
+use self::{
+	arena::Arena,
+	edition::Edition,
+	first::First,
+	memo::{Memo, Memoization},
+	random_access::TokenTree,
+};
 
+pub mod arena;
+pub mod edition;
+pub mod first;
+pub mod memo;
 pub mod random_access;
+pub mod source;
+
+thread_local! {
+	static DEFAULT_SPAN: Cell<Option<Span>> = const { Cell::new(None) };
+}
+
+/// The span for tokens of [`Default`] syntax tree nodes.
+///
+/// While an [`Input`] puts a default in place of missing syntax, that's where the syntax was expected.
+/// Otherwise, it's [`Span::mixed_site()`].
+#[must_use]
+pub fn default_span() -> Span {
+	DEFAULT_SPAN
+		.with(Cell::get)
+		.unwrap_or_else(Span::mixed_site)
+}
+
+/// The offsets of the postfix operators in `tokens` after the first token tree, in ascending order.
+///
+/// Each one only depends on the tokens before it, so a prefix of `tokens` has the same ones up to its end.
+///
+/// That's a `.` (but not one within `..` or `...`), `?`, `[…]` or `(…)`,
+/// except for a method call's `(…)`, which belongs to the `.` before its path segment.
+/// Generic arguments are skipped, so that `a.f::<(A, B)>(c)` is split before the `.`.
+fn postfix_starts(tokens: &[TokenTree]) -> Vec<usize> {
+	let mut starts = Vec::new();
+	let mut after_dot = false;
+	let mut generics_depth = 0_usize;
+	let mut glued_end = 0;
+	for (offset, token_tree) in random_access::trees(tokens) {
+		if offset < glued_end {
+			continue;
+		}
+		glued_end = offset + glued_len(&tokens[offset..]);
+		let is_start = match token_tree {
+			// Generic arguments open and close one `<` or `>` at a time, like in `find_end`.
+			TokenTree::Punct(punct) if matches!(punct.as_char(), '<' | '>') => {
+				glued_end = offset + 1;
+				if punct.as_char() == '<' {
+					generics_depth += 1;
+				} else {
+					generics_depth = generics_depth.saturating_sub(1);
+				}
+				continue;
+			}
+			_ if generics_depth > 0 => continue,
+			TokenTree::Punct(punct) => match punct.as_char() {
+				'.' if glued_end == offset + 1 => {
+					after_dot = true;
+					true
+				}
+				'?' => {
+					after_dot = false;
+					true
+				}
+				_ => continue,
+			},
+			TokenTree::Group(group) => {
+				let is_arguments = mem::take(&mut after_dot);
+				match group.delimiter {
+					Delimiter::Parenthesis => !is_arguments,
+					Delimiter::Bracket => true,
+					Delimiter::Brace | Delimiter::None => false,
+				}
+			}
+			TokenTree::Ident(_) | TokenTree::Literal(_) => continue,
+		};
+		if is_start && offset > 0 {
+			starts.push(offset);
+		}
+	}
+	starts
+}
+
+#[cfg(test)]
+thread_local! {
+	/// How many times a [`Parse::parse`] ran, to test that memoization bounds the work.
+	pub(crate) static PARSE_RUNS: Cell<usize> = const { Cell::new(0) };
+}
 
+/// Calls `f` with [`default_span`] returning `span`.
+fn with_default_span<T>(span: Span, f: impl FnOnce() -> T) -> T {
+	let outer = DEFAULT_SPAN.with(|default_span| default_span.replace(Some(span)));
+	let value = f();
+	DEFAULT_SPAN.with(|default_span| default_span.set(outer));
+	value
+}
+
+/// A position in a [flat token buffer](`random_access`), along with the parse's shared state.
+#[derive(Clone)]
 pub struct Input<'a> {
-	pub cursor: &'a [TokenTree<'a>],
+	/// The tokens that are left.
+	pub cursor: &'a [TokenTree],
+	/// The span of the token tree before [`cursor`](`Input::cursor`), or of the opening delimiter at the start of a group.
+	///
+	/// Missing tokens are suggested to be inserted after it.
+	pub previous: Option<Span>,
+	/// Where errors and warnings are reported.
 	pub diagnostics: &'a DiagnosticsList<'a>,
+	/// Optional packrat memoization, to avoid exponential reparsing of nested alternatives.
+	pub memo: Option<&'a Memo<'a>>,
+	/// Optional arena for [`Node`](`arena::Node`)s, which are boxed otherwise.
+	pub arena: Option<&'a Arena>,
+	/// Whether errors are suspended, which [`Parse::try_parse`] does while parsing speculatively.
+	///
+	/// Suspended errors are recorded so that the attempt fails, but aren't rendered.
+	/// They only reach the user if the caller reports them.
+	pub suspended: bool,
+	/// Decides which words are keywords and which edition-dependent syntax is accepted.
+	pub edition: Edition,
 }
 
 impl<'a> Input<'a> {
+	/// Reports that a `T` was expected at the cursor and returns a default one in its place.
 	pub fn error_expected<T: Parse<'a> + Default>(&mut self) -> T {
-		todo!("If errors aren't suspended, create a diagnostic that evaluates with the given type's self-description.");
-		T::default()
+		self.report_expected::<T>();
+		with_default_span(self.expected_span(), T::default)
+	}
+
+	/// Where something that is missing was expected: at the next token, else after the previous one.
+	pub(crate) fn expected_span(&self) -> Span {
+		self.next_token().map_or_else(
+			|| self.previous.unwrap_or_else(Span::call_site),
+			TokenTree::span,
+		)
+	}
+
+	/// Reports that a `T` was expected at the cursor, for types that can't be defaulted as a whole.
+	pub(crate) fn report_expected<T: Parse<'a>>(&mut self) {
+		let mut diagnostic = Diagnostic::unrendered(self, vec![Expected::of::<T>()]);
+		let first = T::first();
+		let delimiters_only = !(first.any || first.ident || first.literal)
+			&& first.keywords.is_empty()
+			&& first.puncts.is_empty();
+		if delimiters_only && !first.delimiters.is_empty() {
+			diagnostic = diagnostic.with_code(Code::MISSING_DELIMITER);
+		}
+		// Missing tokens belong right after what precedes them, even at the end of input.
+		let edit = match (self.previous, diagnostic.found) {
+			(Some(previous), _) => Some(Edit::InsertAfter(previous)),
+			(None, Some(found)) => Some(Edit::InsertBefore(found.span())),
+			(None, None) => None,
+		};
+		if let (Some(insertion), Some(edit)) = (T::insertion(), edit) {
+			diagnostic = diagnostic.with_suggestion(Suggestion {
+				message: Message::new("insert").arg("insertion", &insertion),
+				edit,
+				replacement: insertion,
+				applicability: Applicability::MachineApplicable,
+			});
+		}
+		// Alternatives that were tried here may have gotten further.
+		let diagnostics = self.diagnostics.merge_failures(self.cursor, diagnostic);
+		self.push_all(diagnostics);
+	}
+
+	/// Reports `diagnostics`, rendering them unless errors are [suspended](`Input::suspended`).
+	fn push_all(&self, diagnostics: Vec<Diagnostic<'a>>) {
+		for diagnostic in diagnostics {
+			self.diagnostics.push(if self.suspended {
+				diagnostic
+			} else {
+				diagnostic.rendered()
+			});
+		}
 	}
 
+	/// Whether only empty invisible groups are left.
+	#[must_use]
 	pub fn is_end(&self) -> bool {
-		self.cursor.into_iter().all(|tt| tt.is_empty())
+		random_access::is_end(self.cursor)
 	}
 }
 
-pub trait Parse<'a>: Sized {
+/// A syntax tree node that can be parsed from an [`Input`].
+///
+/// Parsing never fails: Errors are reported and a default value is returned in place of what's missing.
+pub trait Parse<'a>: Sized + 'a {
+	/// Parses `Self`, reporting any errors to [`Input::diagnostics`].
 	fn parse(input: &mut Input<'a>) -> Self;
 
+	/// Speculatively parses `Self`, with errors [suspended](`Input::suspended`).
+	///
+	/// If the attempt produced any errors, `input`'s cursor is restored and all of its diagnostics are returned as error instead of being reported.
+	/// Otherwise, its diagnostics are reported as usual.
+	///
+	/// Errors of failed attempts are also kept until another attempt at the same position succeeds,
+	/// so that [`Input::error_expected`] can report those of the attempt that got furthest
+	/// or merge them into one "expected one of" error.
 	fn try_parse(input: &mut Input<'a>) -> Fine<Self, DiagnosticsList<'a>> {
-		todo!()
+		let (cursor, previous) = (input.cursor, input.previous);
+		let checkpoint = input.diagnostics.checkpoint();
+		let failures = input.diagnostics.failures_checkpoint();
+		let suspended = mem::replace(&mut input.suspended, true);
+		let outer = input.diagnostics.begin_attempt();
+		let parsed = input.memoized(Self::parse);
+		input.diagnostics.end_attempt(outer);
+		input.suspended = suspended;
+		if input.diagnostics.has_errors_since(checkpoint) {
+			(input.cursor, input.previous) = (cursor, previous);
+			let diagnostics = input.diagnostics.split_off(checkpoint);
+			let admitted = input.admits::<Self>();
+			input
+				.diagnostics
+				.record_failure(cursor, failures, &diagnostics, admitted);
+			(parsed, Err(diagnostics))
+		} else {
+			input.diagnostics.forget_failures(cursor);
+			(parsed, Ok(()))
+		}
+	}
+
+	/// Writes what `Self` looks like, for "expected …" errors.
+	///
+	/// # Errors
+	///
+	/// Iff `w` errors.
+	fn describe(w: &mut dyn Write) -> fmt::Result;
+
+	/// The tokens `Self` can start with, so that alternatives can be ruled out without speculative parsing.
+	///
+	/// Admits anything by default.
+	#[must_use]
+	fn first() -> First {
+		First::any()
+	}
+
+	/// Source text that can be inserted where `Self` is missing, if there is exactly one.
+	///
+	/// [`Input::error_expected`] suggests it as fix. [`None`] by default.
+	#[must_use]
+	fn insertion() -> Option<String> {
+		None
 	}
 
-	fn describe(w: &mut dyn Write);
+	/// Opts into [memoizing](`Memo`) attempts to parse `Self`.
+	///
+	/// This also cuts off left recursion: A parse of `Self` that reaches itself at the same position fails there.
+	/// Returns [`None`] by default, in which case `Self` is parsed anew each time.
+	#[must_use]
+	fn memoization() -> Option<Memoization<Self>> {
+		None
+	}
+
+	/// Whether [`Input::parse`] steps into a leading invisible group to parse `Self` from its contents.
+	///
+	/// Returns `true` by default. Types that keep tokens as they are should return `false`.
+	#[must_use]
+	fn looks_through_invisible_groups() -> bool {
+		true
+	}
 }
 
 impl<'a, T> Parse<'a> for Option<T>
@@ -41,9 +290,18 @@ where
 	T: Parse<'a>,
 {
 	fn parse(input: &mut Input<'a>) -> Self {
+		if !input.admits::<T>() {
+			return None;
+		}
 		let (t, r) = input.try_parse();
 		r.ok().map(|()| t)
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-optional")
+			.arg("item", Expected::of::<T>())
+			.write(w)
+	}
 }
 
 impl<'a, T> Parse<'a> for Box<T>
@@ -54,9 +312,13 @@ where
 		Box::new(input.parse())
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		T::describe(w)
 	}
+
+	fn first() -> First {
+		T::first()
+	}
 }
 
 impl<'a, T> Parse<'a> for Vec<T>
@@ -71,9 +333,10 @@ where
 		vec
 	}
 
-	fn describe(w: &mut dyn Write) {
-		w.write_str("any times ")?;
-		T::describe(w)
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-any-times")
+			.arg("item", Expected::of::<T>())
+			.write(w)
 	}
 }
 
@@ -89,35 +352,46 @@ where
 		vec
 	}
 
-	fn describe(w: &mut dyn Write) {
-		w.write_str("at least one time ")?;
-		T::describe(w)
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-at-least-once")
+			.arg("item", Expected::of::<T>())
+			.write(w)
+	}
+
+	fn first() -> First {
+		T::first()
 	}
 }
 
+/// Defaults to [`Either::Left`].
 impl<'a, L, R> Parse<'a> for Either<L, R>
 where
-	L: Parse<'a>,
+	L: Parse<'a> + Default,
 	R: Parse<'a>,
 {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut Input<'a>) -> Self {
 		if let (l, Ok(())) = input.try_parse() {
 			Self::Left(l)
 		} else if let (r, Ok(())) = input.try_parse() {
 			Self::Right(r)
 		} else {
-			// It might be good to give each Parse implementation a human() method that returns formatting information.
-			todo!()
+			input.report_expected::<Self>();
+			Self::Left(L::default())
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		w.write_str("( ")?;
 		L::describe(w)?;
 		w.write_str("|")?;
 		R::describe(w)?;
 		w.write_str(" )")
 	}
+
+	fn first() -> First {
+		L::first().or(R::first())
+	}
 }
 
 impl<'a, T1> Parse<'a> for (T1,)
@@ -128,9 +402,13 @@ where
 		(input.parse(),)
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		T1::describe(w)
 	}
+
+	fn first() -> First {
+		T1::first()
+	}
 }
 impl<'a, T1, T2> Parse<'a> for (T1, T2)
 where
@@ -141,11 +419,15 @@ where
 		(input.parse(), input.parse())
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		T1::describe(w)?;
 		w.write_str(" ")?;
 		T2::describe(w)
 	}
+
+	fn first() -> First {
+		T1::first()
+	}
 }
 impl<'a, T1, T2, T3> Parse<'a> for (T1, T2, T3)
 where
@@ -157,13 +439,17 @@ where
 		(input.parse(), input.parse(), input.parse())
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		T1::describe(w)?;
 		w.write_str(" ")?;
 		T2::describe(w)?;
 		w.write_str(" ")?;
 		T3::describe(w)
 	}
+
+	fn first() -> First {
+		T1::first()
+	}
 }
 impl<'a, T1, T2, T3, T4> Parse<'a> for (T1, T2, T3, T4)
 where
@@ -175,7 +461,7 @@ where
 	fn parse(input: &mut Input<'a>) -> Self {
 		(input.parse(), input.parse(), input.parse(), input.parse())
 	}
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		T1::describe(w)?;
 		w.write_str(" ")?;
 		T2::describe(w)?;
@@ -184,6 +470,10 @@ where
 		w.write_str(" ")?;
 		T4::describe(w)
 	}
+
+	fn first() -> First {
+		T1::first()
+	}
 }
 impl<'a, T1, T2, T3, T4, T5> Parse<'a> for (T1, T2, T3, T4, T5)
 where
@@ -202,7 +492,7 @@ where
 			input.parse(),
 		)
 	}
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		T1::describe(w)?;
 		w.write_str(" ")?;
 		T2::describe(w)?;
@@ -213,6 +503,10 @@ where
 		w.write_str(" ")?;
 		T5::describe(w)
 	}
+
+	fn first() -> First {
+		T1::first()
+	}
 }
 impl<'a, T1, T2, T3, T4, T5, T6> Parse<'a> for (T1, T2, T3, T4, T5, T6)
 where
@@ -233,7 +527,7 @@ where
 			input.parse(),
 		)
 	}
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		T1::describe(w)?;
 		w.write_str(" ")?;
 		T2::describe(w)?;
@@ -246,25 +540,394 @@ where
 		w.write_str(" ")?;
 		T6::describe(w)
 	}
+
+	fn first() -> First {
+		T1::first()
+	}
 }
 
 impl<'a> Input<'a> {
+	/// Parses a `T`, looking through leading invisible groups.
 	pub fn parse<T: Parse<'a>>(&mut self) -> T {
-		//TODO: Auto-descend into groups here.
-		T::parse(self)
+		self.diagnostics.enter(self.cursor);
+		self.enter_invisible_groups::<T>();
+		self.memoized(T::parse)
 	}
 
+	/// Speculatively parses a `T`, looking through leading invisible groups.
+	///
+	/// Such groups appear mainly around `macro_rules!` fragment captures like `$e:expr`.
 	pub fn try_parse<T: Parse<'a>>(&mut self) -> Fine<T, DiagnosticsList<'a>> {
-		T::try_parse(self)
+		let (cursor, previous) = (self.cursor, self.previous);
+		self.diagnostics.enter(cursor);
+		self.enter_invisible_groups::<T>();
+		let (parsed, result) = T::try_parse(self);
+		if result.is_err() {
+			(self.cursor, self.previous) = (cursor, previous);
+		}
+		(parsed, result)
+	}
+
+	/// Parses a `T` like [`Input::parse`], along with the span of the invisible group it was parsed from, if it spans exactly that.
+	pub(crate) fn parse_captured<T: Parse<'a>>(&mut self) -> (T, Option<DelimSpan>) {
+		self.skip_empty_groups();
+		let group = match self.cursor.first() {
+			Some(token_tree @ TokenTree::Group(group)) if group.delimiter == Delimiter::None => {
+				Some((group.delim_span, token_tree.flat_len()))
+			}
+			_ => None,
+		};
+
+		let before = self.cursor.len();
+		let parsed = self.parse();
+		let consumed = before - self.cursor.len();
+		(
+			parsed,
+			group
+				.filter(|&(_, flat_len)| consumed == flat_len)
+				.map(|(delim_span, _)| delim_span),
+		)
 	}
 
+	/// An input over the contents of the group at the cursor, if there is one.
+	pub(crate) fn group_contents(&self) -> Option<Self> {
+		let Some(TokenTree::Group(group)) = self.cursor.first() else {
+			return None;
+		};
+		Some(Self {
+			cursor: random_access::group_contents(self.cursor)?,
+			previous: Some(group.delim_span.open()),
+			..self.clone()
+		})
+	}
+
+	/// Skips empty invisible groups and steps into non-empty ones, if `T` [looks through them](`Parse::looks_through_invisible_groups`).
+	///
+	/// Since a group's contents directly follow it in the [flat buffer](`random_access`),
+	/// this peels each group once instead of parsing `T` both around and within it.
+	///
+	/// Types that [admit anything](`First::any`) only step into a group that spans the rest of the input.
+	/// Otherwise, the group stays a single token tree for them, so that an operand like `$e` in `$e * 2`
+	/// is split off whole and then parsed on its own.
+	fn enter_invisible_groups<T: Parse<'a>>(&mut self) {
+		loop {
+			self.skip_empty_groups();
+			match self.cursor.first() {
+				Some(token_tree @ TokenTree::Group(group))
+					if group.delimiter == Delimiter::None
+						&& T::looks_through_invisible_groups()
+						&& (random_access::is_end(&self.cursor[token_tree.flat_len()..])
+							|| !T::first().any) =>
+				{
+					self.previous = Some(group.delim_span.open());
+					self.cursor = &self.cursor[1..];
+				}
+				_ => break,
+			}
+		}
+	}
+
+	fn skip_empty_groups(&mut self) {
+		if let Some(first) = self.cursor.first() {
+			self.advance(first.empty_run().min(self.cursor.len()));
+		}
+	}
+
+	/// Whether a `T` could be parsed here without errors, without moving the cursor.
+	#[must_use]
 	pub fn peek<T: Parse<'a>>(&self) -> bool {
-		self.clone().try_parse::<T>().is_ok()
+		self.admits::<T>()
+			&& self
+				.diagnostics
+				.probing(|| self.clone().try_parse::<T>().is_ok())
 	}
 
+	/// Whether `T` could be parsed here, judging only by [its FIRST set](`Parse::first`).
+	#[must_use]
+	pub fn admits<T: Parse<'a>>(&self) -> bool {
+		T::first().admits(self)
+	}
+
+	/// The next token tree, looking past empty invisible groups.
+	pub fn next_token(&self) -> Option<&'a TokenTree> {
+		let empty_run = self.cursor.first().map_or(0, TokenTree::empty_run);
+		self.cursor.get(empty_run)
+	}
+
+	/// Skips `token_tree_count` token trees, returning them.
 	pub fn consume(&mut self, token_tree_count: usize) -> &'a [TokenTree] {
-		let (a, b) = self.cursor.split_at(token_tree_count);
+		let len = random_access::trees(self.cursor)
+			.nth(token_tree_count)
+			.map_or(self.cursor.len(), |(offset, _)| offset);
+		self.advance(len)
+	}
+
+	/// Advances the cursor by `len` slots, which must end on a token tree boundary.
+	pub(crate) fn advance(&mut self, len: usize) -> &'a [TokenTree] {
+		let (a, b) = self.cursor.split_at(len);
+		if let Some((_, last)) = random_access::trees(a).last() {
+			self.previous = Some(last.span());
+		}
 		self.cursor = b;
 		a
 	}
+
+	/// Parses a `T` from only the tokens before the first position where `terminator` holds, then advances past them.
+	///
+	/// Tokens that `T` leaves over in that prefix are reported, so a broken clause can't eat the rest of the input.
+	pub fn parse_to<T: Parse<'a>>(&mut self, terminator: impl Fn(&Input<'a>) -> bool) -> T {
+		let end = self.find_end(terminator);
+		let parsed = self.parse_exhaustively(&self.cursor[..end]);
+		self.advance(end);
+		parsed
+	}
+
+	/// Parses a left-recursive `L R` sequence from only the tokens before the first position where `terminator` holds, then advances past them.
+	///
+	/// `R` is split off at the start of the last postfix operator (a `.`, `?`, `[…]` or call's `(…)`),
+	/// so `L` is only ever parsed from strictly fewer tokens and chains like `a.b()?.c[0]` nest to the left.
+	pub fn right_aligned_left_recursive_parse<L, R>(
+		&mut self,
+		terminator: impl Fn(&Input<'a>) -> bool,
+	) -> (L, R)
+	where
+		L: Parse<'a> + Default,
+		R: Parse<'a> + Default,
+	{
+		let end = self.find_end(terminator);
+		let prefix = &self.cursor[..end];
+
+		let right = self.postfix_start(prefix).and_then(|split| {
+			let mut suffix = self.after(&prefix[..split]);
+			suffix.cursor = &prefix[split..];
+			match suffix.try_parse() {
+				(r, Ok(())) if suffix.is_end() => Some((split, r)),
+				_ => None,
+			}
+		});
+
+		let parsed = match right {
+			Some((split, r)) => (self.parse_exhaustively(&prefix[..split]), r),
+			None => self.error_expected(),
+		};
+
+		self.advance(end);
+		parsed
+	}
+
+	/// The offset of the last of [`postfix_starts`] in `prefix`.
+	///
+	/// `L` is parsed from a prefix of the same tokens, so with a [`Memo`] a whole chain is only scanned once.
+	fn postfix_start(&self, prefix: &'a [TokenTree]) -> Option<usize> {
+		match self.memo {
+			Some(memo) => memo.postfix_start(prefix, postfix_starts),
+			None => postfix_starts(prefix).last().copied(),
+		}
+	}
+
+	/// The offset of the first token tree where `terminator` holds.
+	///
+	/// Glued punctuation is a single token, so `::` isn't terminated by its second `:`.
+	/// Only a leading `<` or `>` is split off, since generic arguments open and close one at a time, like in `Vec<Vec<u8>>`.
+	/// A turbofish's generic arguments are skipped as a whole, so the `,` in `f::<A, B>()` doesn't end anything.
+	pub(crate) fn find_end(&self, terminator: impl Fn(&Input<'a>) -> bool) -> usize {
+		let mut glued_end = 0;
+		random_access::trees(self.cursor)
+			.find(|&(offset, token_tree)| {
+				if offset < glued_end {
+					return false;
+				}
+				glued_end = match token_tree {
+					TokenTree::Punct(punct) if matches!(punct.as_char(), '<' | '>') => offset + 1,
+					_ if spells(&self.cursor[offset..], "::<".chars()) => {
+						random_access::skip_angle_brackets(self.cursor, offset + 2)
+					}
+					_ => offset + glued_len(&self.cursor[offset..]),
+				};
+				terminator(&self.with_cursor(&self.cursor[offset..]))
+			})
+			.map_or(self.cursor.len(), |(offset, _)| offset)
+	}
+
+	/// Parses a `T` from `tokens`, reporting any that are left over.
+	pub(crate) fn parse_exhaustively<T: Parse<'a>>(&self, tokens: &'a [TokenTree]) -> T {
+		let mut input = self.with_cursor(tokens);
+		let parsed = input.parse();
+		if !input.is_end() {
+			// A speculative `T` may have stopped short of an element that got further before failing.
+			let diagnostic = Diagnostic::unrendered(&input, vec![Expected::end_of_input()])
+				.with_code(Code::TRAILING_TOKENS);
+			let diagnostics =
+				input.framed(|input| input.diagnostics.merge_failures(input.cursor, diagnostic));
+			input.push_all(diagnostics);
+		}
+		parsed
+	}
+
+	/// Calls `parse`, unless parsing a `T` at the cursor is memoized or already in progress.
+	pub(crate) fn memoized<T: Parse<'a>>(&mut self, parse: impl FnOnce(&mut Self) -> T) -> T {
+		let Some(memoization) = T::memoization() else {
+			return self.framed(parse);
+		};
+
+		// Reaching the same parse again without consuming anything would recurse forever,
+		// which happens where alternatives continue after a missing first token.
+		if !self
+			.diagnostics
+			.begin_progress(memoization.marker(), self.cursor)
+		{
+			self.report_expected::<T>();
+			return with_default_span(self.expected_span(), || memoization.placeholder());
+		}
+		let parsed = self.memoized_with(parse, &memoization);
+		self.diagnostics.end_progress();
+		parsed
+	}
+
+	/// Replays or records an attempt in [`Input::memo`], if there is one.
+	fn memoized_with<T: Parse<'a>>(
+		&mut self,
+		parse: impl FnOnce(&mut Self) -> T,
+		memoization: &Memoization<T>,
+	) -> T {
+		let Some(memo) = self.memo else {
+			return self.framed(parse);
+		};
+
+		if let Some((parsed, consumed, diagnostics)) =
+			with_default_span(self.expected_span(), || memo.recall(self, memoization))
+		{
+			// Nested speculative attempts may have left their diagnostics unrendered.
+			self.push_all(diagnostics.into_iter().collect());
+			self.advance(consumed);
+			return parsed;
+		}
+
+		let start = self.clone();
+		let checkpoint = self.diagnostics.checkpoint();
+		let parsed = self.framed(parse);
+		let failed = self.diagnostics.has_errors_since(checkpoint);
+		memo.record(
+			&start,
+			memoization,
+			start.cursor.len() - self.cursor.len(),
+			self.diagnostics.copy_since(checkpoint),
+			(!failed).then_some(&parsed),
+		);
+		parsed
+	}
+
+	/// Calls `parse` with its own frame for [merging failures](`DiagnosticsList::merge_failures`).
+	fn framed<T>(&mut self, parse: impl FnOnce(&mut Self) -> T) -> T {
+		#[cfg(test)]
+		PARSE_RUNS.with(|runs| runs.set(runs.get() + 1));
+		let outer = self.diagnostics.begin_parse();
+		let parsed = parse(self);
+		self.diagnostics.end_parse(outer);
+		parsed
+	}
+
+	pub(crate) fn with_cursor(&self, cursor: &'a [TokenTree]) -> Self {
+		Self {
+			cursor,
+			..self.clone()
+		}
+	}
+
+	/// An input with an empty cursor right after `tokens`, to parse the tokens that follow them with [`Input::parse_exhaustively`].
+	pub(crate) fn after(&self, tokens: &'a [TokenTree]) -> Self {
+		let mut input = self.with_cursor(tokens);
+		input.advance(tokens.len());
+		input
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+
+	use crate::{
+		expressions::{
+			operator_expressions::{
+				ArithmeticOrLogicalExpression, ArithmeticOrLogicalOp, OperatorExpression,
+			},
+			Expression, ExpressionWithoutBlock, ExpressionWithoutBlockContent,
+		},
+		identifiers::Identifier,
+		parse2,
+		tokens::{punctuation::Semi, Captured},
+	};
+
+	/// `source` in an invisible group, like a `macro_rules!` fragment capture.
+	fn invisible(source: &str) -> TokenTree {
+		Group::new(Delimiter::None, source.parse().unwrap()).into()
+	}
+
+	fn tokens(token_trees: impl IntoIterator<Item = TokenTree>) -> TokenStream {
+		token_trees.into_iter().collect()
+	}
+
+	fn arithmetic<'e, 'a>(expression: &'e Expression<'a>) -> &'e ArithmeticOrLogicalExpression<'a> {
+		let Expression::ExpressionWithoutBlock(ExpressionWithoutBlock {
+			variant:
+				ExpressionWithoutBlockContent::OperatorExpression(
+					OperatorExpression::ArithmeticOrLogicalExpression(arithmetic),
+				),
+			..
+		}) = expression
+		else {
+			panic!("not an arithmetic or logical expression: {expression:?}")
+		};
+		arithmetic
+	}
+
+	#[test]
+	fn captured_operands_stay_whole() {
+		let source = tokens(
+			[invisible("1 + 2")]
+				.into_iter()
+				.chain("* 3".parse::<TokenStream>().unwrap()),
+		);
+		parse2(source, |parsing| {
+			let (expression, diagnostics) = parsing.parse::<Expression>();
+			assert!(diagnostics.is_empty());
+
+			let mul = arithmetic(&expression);
+			assert!(matches!(mul.op, ArithmeticOrLogicalOp::Mul(_)));
+			assert!(mul.left.captured().is_some());
+			assert!(mul.right.captured().is_none());
+			assert!(matches!(
+				arithmetic(&mul.left).op,
+				ArithmeticOrLogicalOp::Add(_)
+			));
+		});
+	}
+
+	#[test]
+	fn captures_are_recorded_for_whole_groups_only() {
+		parse2(tokens([invisible("x")]), |parsing| {
+			let (captured, diagnostics) = parsing.parse::<Captured<Identifier>>();
+			assert!(diagnostics.is_empty());
+			assert!(captured.delim_span.is_some());
+		});
+		parse2(tokens([invisible("x;")]), |parsing| {
+			let ((captured, _), diagnostics) = parsing.parse::<(Captured<Identifier>, Semi)>();
+			assert!(diagnostics.is_empty());
+			assert!(captured.delim_span.is_none());
+		});
+		parse2("x".parse().unwrap(), |parsing| {
+			let (captured, diagnostics) = parsing.parse::<Captured<Identifier>>();
+			assert!(diagnostics.is_empty());
+			assert!(captured.delim_span.is_none());
+		});
+	}
+
+	#[test]
+	fn empty_invisible_groups_are_skipped() {
+		let source = tokens([invisible(""), invisible(";"), invisible("")]);
+		parse2(source, |parsing| {
+			let (_, diagnostics) = parsing.parse::<Semi>();
+			assert!(diagnostics.is_empty());
+		});
+	}
 }
@@ -0,0 +1,328 @@
+//! Arena allocation of recursive nodes, as alternative to boxing them one by one.
+
+use std::{
+	alloc::{self, Layout},
+	cell::{Cell, RefCell},
+	fmt::{self, Debug, Write},
+	mem::ManuallyDrop,
+	ops::{Deref, DerefMut},
+	ptr::{self, NonNull},
+};
+
+use proc_macro2::extra::DelimSpan;
+
+use super::{first::First, Input, Parse};
+
+/// A bump allocator for [`Node`]s.
+///
+/// Pass it to [`Source::parse`](`super::source::Source::parse`) or use [`Parsing::in_arena`](`crate::Parsing::in_arena`)
+/// to allocate nodes here instead of boxing them.
+/// Nodes from speculative attempts that failed stay allocated until the arena is dropped,
+/// which frees everything at once.
+///
+/// The arena only provides memory: Each [`Node`] drops its value itself.
+#[derive(Default)]
+pub struct Arena {
+	chunks: RefCell<Vec<Chunk>>,
+}
+
+struct Chunk {
+	start: NonNull<u8>,
+	layout: Layout,
+	used: Cell<usize>,
+}
+
+const MIN_CHUNK_SIZE: usize = 4096;
+
+impl Arena {
+	/// An empty arena.
+	#[must_use]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Moves `value` into the arena.
+	///
+	/// The arena never drops it, so that it can't outlive what it borrows from.
+	#[allow(clippy::mut_from_ref)] // Each call returns a fresh slot.
+	fn alloc<T>(&self, value: T) -> &mut ManuallyDrop<T> {
+		let layout = Layout::new::<ManuallyDrop<T>>();
+		let slot = self.alloc_layout(layout).cast::<ManuallyDrop<T>>();
+		// SAFETY: `slot` is valid for writes of `T`, unaliased and lives as long as `self`.
+		unsafe {
+			ptr::write(slot.as_ptr(), ManuallyDrop::new(value));
+			&mut *slot.as_ptr()
+		}
+	}
+
+	fn alloc_layout(&self, layout: Layout) -> NonNull<u8> {
+		let mut chunks = self.chunks.borrow_mut();
+		if let Some(chunk) = chunks.last() {
+			if let Some(slot) = chunk.bump(layout) {
+				return slot;
+			}
+		}
+
+		let size = chunks
+			.last()
+			.map_or(MIN_CHUNK_SIZE, |chunk| chunk.layout.size() * 2)
+			.max(layout.size());
+		let chunk = Chunk::new(
+			Layout::from_size_align(size, layout.align().max(16)).expect("valid chunk layout"),
+		);
+		let slot = chunk.bump(layout).expect("fits into new chunk");
+		chunks.push(chunk);
+		slot
+	}
+
+	/// How many bytes the arena has reserved so far.
+	pub fn allocated_bytes(&self) -> usize {
+		self.chunks
+			.borrow()
+			.iter()
+			.map(|chunk| chunk.layout.size())
+			.sum()
+	}
+}
+
+impl Chunk {
+	fn new(layout: Layout) -> Self {
+		// SAFETY: `layout` isn't zero-sized, since it's at least `MIN_CHUNK_SIZE` or the size of a non-zero-sized value.
+		let start = NonNull::new(unsafe { alloc::alloc(layout) })
+			.unwrap_or_else(|| alloc::handle_alloc_error(layout));
+		Self {
+			start,
+			layout,
+			used: Cell::new(0),
+		}
+	}
+
+	fn bump(&self, layout: Layout) -> Option<NonNull<u8>> {
+		let address = self.start.as_ptr() as usize + self.used.get();
+		let offset = self.used.get() + (address.wrapping_neg() & (layout.align() - 1));
+		let end = offset.checked_add(layout.size())?;
+		if end > self.layout.size() {
+			return None;
+		}
+		self.used.set(end);
+		// SAFETY: `offset` is within the chunk.
+		Some(unsafe { NonNull::new_unchecked(self.start.as_ptr().add(offset)) })
+	}
+}
+
+impl Drop for Arena {
+	fn drop(&mut self) {
+		for chunk in self.chunks.get_mut().drain(..) {
+			// SAFETY: Allocated in `Chunk::new` with this layout.
+			unsafe { alloc::dealloc(chunk.start.as_ptr(), chunk.layout) }
+		}
+	}
+}
+
+impl Debug for Arena {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Arena")
+			.field("chunks", &self.chunks.borrow().len())
+			.field("allocated_bytes", &self.allocated_bytes())
+			.finish_non_exhaustive()
+	}
+}
+
+/// An owning pointer to a recursive AST node, in [`Input::arena`] if there is one or else on the heap.
+///
+/// Like a [`Box`], it drops its value when dropped itself.
+///
+/// Parsed nodes also remember whether they came from exactly one invisible group,
+/// like those around `macro_rules!` fragment captures.
+pub struct Node<'a, T> {
+	slot: Slot<'a, T>,
+	captured: Option<DelimSpan>,
+}
+
+enum Slot<'a, T> {
+	Boxed(Box<T>),
+	Arena(&'a mut ManuallyDrop<T>),
+}
+
+impl<'a, T> Node<'a, T> {
+	/// Moves `value` into `input`'s arena, if it has one, or else into a [`Box`].
+	pub fn new(input: &Input<'a>, value: T) -> Self {
+		Self {
+			slot: match input.arena {
+				Some(arena) => Slot::Arena(arena.alloc(value)),
+				None => Slot::Boxed(Box::new(value)),
+			},
+			captured: None,
+		}
+	}
+
+	fn boxed(value: T) -> Self {
+		Self {
+			slot: Slot::Boxed(Box::new(value)),
+			captured: None,
+		}
+	}
+
+	/// The span of the invisible group this node was parsed from, if it spans exactly that.
+	#[must_use]
+	pub fn captured(&self) -> Option<DelimSpan> {
+		self.captured
+	}
+}
+
+impl<T> Drop for Node<'_, T> {
+	fn drop(&mut self) {
+		if let Slot::Arena(node) = &mut self.slot {
+			// SAFETY: The slot was filled by `Arena::alloc` for this node only, and isn't used afterwards.
+			unsafe { ManuallyDrop::drop(node) }
+		}
+	}
+}
+
+impl<T> Deref for Node<'_, T> {
+	type Target = T;
+
+	fn deref(&self) -> &Self::Target {
+		match &self.slot {
+			Slot::Boxed(boxed) => boxed,
+			Slot::Arena(node) => node,
+		}
+	}
+}
+
+impl<T> DerefMut for Node<'_, T> {
+	fn deref_mut(&mut self) -> &mut Self::Target {
+		match &mut self.slot {
+			Slot::Boxed(boxed) => boxed,
+			Slot::Arena(node) => node,
+		}
+	}
+}
+
+impl<T: Debug> Debug for Node<'_, T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		T::fmt(self, f)
+	}
+}
+
+/// Clones are boxed, since there's no arena at hand.
+impl<T: Clone> Clone for Node<'_, T> {
+	fn clone(&self) -> Self {
+		let mut clone = Self::boxed(T::clone(self));
+		clone.captured = self.captured;
+		clone
+	}
+}
+
+impl<T: Default> Default for Node<'_, T> {
+	fn default() -> Self {
+		Self::boxed(T::default())
+	}
+}
+
+impl<'a, T> Parse<'a> for Node<'a, T>
+where
+	T: Parse<'a>,
+{
+	fn parse(input: &mut Input<'a>) -> Self {
+		let (value, captured) = input.parse_captured();
+		let mut node = Self::new(input, value);
+		node.captured = captured;
+		node
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		T::describe(w)
+	}
+
+	fn first() -> First {
+		T::first()
+	}
+
+	fn looks_through_invisible_groups() -> bool {
+		false
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{
+		cell::Cell,
+		fmt::{self, Write},
+	};
+
+	use crate::{
+		expressions::Expression,
+		help::DiagnosticsList,
+		identifiers::Identifier,
+		io::{source::Source, Input, Parse},
+		parse_str,
+		tokens::punctuation::Semi,
+	};
+
+	use super::{Arena, Node};
+
+	#[test]
+	fn nodes_are_allocated_in_the_arena() {
+		let arena = Arena::new();
+		let source: Source = "a.b()?.c[0].await".parse().unwrap();
+		let diagnostics = DiagnosticsList::default();
+		let boxed: Expression = source.parse(&diagnostics, None, None);
+		assert_eq!(arena.allocated_bytes(), 0);
+		let in_arena: Expression = source.parse(&diagnostics, None, Some(&arena));
+		assert!(arena.allocated_bytes() > 0);
+		assert!(diagnostics.is_empty(), "{diagnostics:?}");
+		assert_eq!(format!("{in_arena:?}"), format!("{boxed:?}"));
+	}
+
+	thread_local! {
+		static ALIVE: Cell<isize> = const { Cell::new(0) };
+	}
+
+	/// Counts how many of it are alive.
+	struct Counted;
+
+	impl Counted {
+		fn new() -> Self {
+			ALIVE.with(|alive| alive.set(alive.get() + 1));
+			Self
+		}
+	}
+
+	impl Default for Counted {
+		fn default() -> Self {
+			Self::new()
+		}
+	}
+
+	impl Drop for Counted {
+		fn drop(&mut self) {
+			ALIVE.with(|alive| alive.set(alive.get() - 1));
+		}
+	}
+
+	impl Parse<'_> for Counted {
+		fn parse(input: &mut Input<'_>) -> Self {
+			let _: Identifier = input.parse();
+			Self::new()
+		}
+
+		fn describe(w: &mut dyn Write) -> fmt::Result {
+			Identifier::describe(w)
+		}
+	}
+
+	#[test]
+	fn failed_attempts_drop_their_nodes_once() {
+		let alive = parse_str("a; b; c d", |parsing| {
+			let parsing = parsing.in_arena();
+			let (parsed, _): (Vec<(Node<Counted>, Semi)>, _) = parsing.parse();
+			assert!(parsing.arena().allocated_bytes() > 0);
+			assert_eq!(parsed.len(), 2);
+			ALIVE.with(Cell::get)
+		})
+		.unwrap();
+		assert_eq!(alive, 2);
+		assert_eq!(ALIVE.with(Cell::get), 0);
+	}
+}
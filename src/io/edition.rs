@@ -0,0 +1,63 @@
+//! Rust editions, which change which words are keywords and which syntax is accepted.
+
+use std::{fmt::Display, str::FromStr};
+
+/// The edition to parse as, which should be that of the crate the tokens are from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Edition {
+	/// Rust 2015.
+	Edition2015,
+	/// Rust 2018.
+	Edition2018,
+	/// Rust 2021.
+	#[default]
+	Edition2021,
+	/// Rust 2024.
+	Edition2024,
+}
+
+impl Edition {
+	/// Whether trait objects may be written without `dyn`, like `Box<Trait>`.
+	#[must_use]
+	pub fn allows_bare_trait_objects(self) -> bool {
+		self < Self::Edition2021
+	}
+
+	/// Whether range patterns may use `...`, like `0...9`.
+	#[must_use]
+	pub fn allows_obsolete_range_patterns(self) -> bool {
+		self < Self::Edition2021
+	}
+
+	/// The edition's year, like `2021`.
+	#[must_use]
+	pub fn year(self) -> u16 {
+		match self {
+			Self::Edition2015 => 2015,
+			Self::Edition2018 => 2018,
+			Self::Edition2021 => 2021,
+			Self::Edition2024 => 2024,
+		}
+	}
+}
+
+impl Display for Edition {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		self.year().fmt(f)
+	}
+}
+
+/// Parses a year like `"2021"`, as in `Cargo.toml`.
+impl FromStr for Edition {
+	type Err = String;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		match s {
+			"2015" => Ok(Self::Edition2015),
+			"2018" => Ok(Self::Edition2018),
+			"2021" => Ok(Self::Edition2021),
+			"2024" => Ok(Self::Edition2024),
+			_ => Err(format!("unknown edition `{s}`")),
+		}
+	}
+}
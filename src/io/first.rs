@@ -0,0 +1,114 @@
+//! FIRST sets, to rule out alternatives without speculative parsing.
+
+use proc_macro2::Delimiter;
+
+use super::{random_access::TokenTree, Input};
+
+/// The tokens a [`Parse`](`super::Parse`) implementation can start with.
+///
+/// Sets are combined with [`First::or`].
+/// Leading parts that may be empty (like [`Option`] or [`Vec`]) must be combined with what follows them.
+#[derive(Debug, Clone, Default)]
+pub struct First {
+	/// Admits any token and the end of input, for types that may be empty or that don't declare their FIRST set.
+	pub any: bool,
+	/// Admitted keywords, by text.
+	pub keywords: Vec<&'static str>,
+	/// Admitted first characters of punctuation.
+	pub puncts: Vec<char>,
+	/// Admitted groups.
+	pub delimiters: Vec<Delimiter>,
+	/// Any [`Ident`](`proc_macro2::Ident`), including keywords.
+	pub ident: bool,
+	/// Any [`Literal`](`proc_macro2::Literal`).
+	pub literal: bool,
+}
+
+impl First {
+	/// Admits anything.
+	#[must_use]
+	pub fn any() -> Self {
+		Self {
+			any: true,
+			..Self::default()
+		}
+	}
+
+	/// Admits `keyword`.
+	#[must_use]
+	pub fn keyword(keyword: &'static str) -> Self {
+		Self {
+			keywords: vec![keyword],
+			..Self::default()
+		}
+	}
+
+	/// Admits punctuation starting with `punct`.
+	#[must_use]
+	pub fn punct(punct: char) -> Self {
+		Self {
+			puncts: vec![punct],
+			..Self::default()
+		}
+	}
+
+	/// Admits groups delimited by `delimiter`.
+	#[must_use]
+	pub fn delimiter(delimiter: Delimiter) -> Self {
+		Self {
+			delimiters: vec![delimiter],
+			..Self::default()
+		}
+	}
+
+	/// Admits any identifier.
+	#[must_use]
+	pub fn ident() -> Self {
+		Self {
+			ident: true,
+			..Self::default()
+		}
+	}
+
+	/// Admits any literal.
+	#[must_use]
+	pub fn literal() -> Self {
+		Self {
+			literal: true,
+			..Self::default()
+		}
+	}
+
+	/// The union of `self` and `other`.
+	#[must_use]
+	pub fn or(mut self, other: Self) -> Self {
+		self.any |= other.any;
+		self.keywords.extend(other.keywords);
+		self.puncts.extend(other.puncts);
+		self.delimiters.extend(other.delimiters);
+		self.ident |= other.ident;
+		self.literal |= other.literal;
+		self
+	}
+
+	/// Whether parsing at `input` could succeed, judging only by its first token.
+	///
+	/// Invisible groups are always admitted, since they may be looked through.
+	#[must_use]
+	pub fn admits(&self, input: &Input<'_>) -> bool {
+		if self.any {
+			return true;
+		}
+		match input.next_token() {
+			None => false,
+			Some(TokenTree::Group(group)) => {
+				group.delimiter == Delimiter::None || self.delimiters.contains(&group.delimiter)
+			}
+			Some(TokenTree::Ident(ident)) => {
+				self.ident || self.keywords.iter().any(|keyword| ident == keyword)
+			}
+			Some(TokenTree::Punct(punct)) => self.puncts.contains(&punct.as_char()),
+			Some(TokenTree::Literal(_)) => self.literal,
+		}
+	}
+}
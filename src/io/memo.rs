@@ -0,0 +1,229 @@
+//! Packrat memoization of parse attempts.
+
+use std::{any::TypeId, cell::RefCell, collections::HashMap, ptr::NonNull};
+
+use crate::help::DiagnosticsList;
+
+use super::{edition::Edition, random_access::TokenTree, Input};
+
+/// A memo table of parse attempts, keyed by cursor position and parsed type.
+///
+/// Share one between [`Input`]s over the same tokens to never parse the same `T` from the same position twice.
+/// Attempts are replayed along with the diagnostics they produced,
+/// but only for types that opt in through [`Parse::memoization`](`super::Parse::memoization`).
+///
+/// Nested alternatives mostly reparse what succeeded or failed before, like the left side of each postfix in `a.b().c().d()`.
+/// Successes replay a clone of what was parsed, failures a placeholder.
+#[derive(Default)]
+pub struct Memo<'a> {
+	attempts: RefCell<HashMap<Key, Attempt<'a>>>,
+	/// Postfix operator offsets by the address of the tokens they were scanned from, along with how many slots were scanned.
+	postfix_starts: RefCell<HashMap<usize, (usize, Vec<usize>)>>,
+	/// How many slots from each address were found to be one operand, without infix operators.
+	operands: RefCell<HashMap<usize, usize>>,
+}
+
+/// Cursor address, cursor length, [marker](`Memoization::new`) type, edition,
+/// whether errors were [suspended](`Input::suspended`) and whether only lookahead was being parsed.
+///
+/// The length is part of the key because the same position can be parsed with different bounds.
+/// Lookahead doesn't merge failures, so its errors read differently.
+type Key = (usize, usize, TypeId, Edition, bool, bool);
+
+struct Attempt<'a> {
+	consumed: usize,
+	diagnostics: DiagnosticsList<'a>,
+	/// What was parsed, unless it failed.
+	parsed: Option<Stored>,
+}
+
+/// A boxed value, of a type only the [`Memoization`] it was stored with knows.
+///
+/// That's so values of different types share one table, and so it borrows nothing as far as drop checking is concerned.
+struct Stored {
+	value: NonNull<()>,
+	drop: unsafe fn(NonNull<()>),
+}
+
+impl Stored {
+	fn new<T>(value: T) -> Self {
+		/// # Safety
+		///
+		/// `value` must come from `Stored::new::<T>` and not be used afterwards.
+		unsafe fn drop_boxed<T>(value: NonNull<()>) {
+			drop(Box::from_raw(value.cast::<T>().as_ptr()));
+		}
+
+		Self {
+			value: NonNull::from(Box::leak(Box::new(value))).cast(),
+			drop: drop_boxed::<T>,
+		}
+	}
+}
+
+impl Drop for Stored {
+	fn drop(&mut self) {
+		// SAFETY: `self.drop` belongs to the type `self.value` was stored as, and `self` isn't used afterwards.
+		unsafe { (self.drop)(self.value) }
+	}
+}
+
+/// How attempts to parse a `T` are [memoized](`Memo`).
+pub struct Memoization<T> {
+	marker: TypeId,
+	placeholder: fn() -> T,
+	clone: fn(&T) -> T,
+}
+
+impl<T: Default + Clone> Memoization<T> {
+	/// Memoizes attempts under `Marker`.
+	///
+	/// Replayed failures produce [`T::default()`](`Default::default`) in place of what was parsed,
+	/// since the diagnostics are errors anyway.
+	/// So do attempts that reach a parse of the same `T` at the same position while it's still in progress.
+	/// Replayed successes produce a clone of what was parsed.
+	///
+	/// # Safety
+	///
+	/// `Marker` must be `T` with all lifetimes `'static`, so that no other type is memoized under it.
+	/// Successes are replayed as whatever type was memoized under their marker.
+	///
+	/// Dropping a clone of `T` must not use what it borrows, since the [`Memo`] may be dropped after that.
+	#[must_use]
+	pub unsafe fn new<Marker: 'static>() -> Self {
+		Self {
+			marker: TypeId::of::<Marker>(),
+			placeholder: T::default,
+			clone: T::clone,
+		}
+	}
+}
+
+impl<T> Memoization<T> {
+	pub(crate) fn marker(&self) -> TypeId {
+		self.marker
+	}
+
+	pub(crate) fn placeholder(&self) -> T {
+		(self.placeholder)()
+	}
+}
+
+impl<'a> Memo<'a> {
+	/// Returns what was parsed (or a placeholder), the consumed length and the diagnostics of a memoized attempt to parse a `T` at `input`'s cursor, if any.
+	pub(crate) fn recall<T>(
+		&self,
+		input: &Input<'a>,
+		memoization: &Memoization<T>,
+	) -> Option<(T, usize, DiagnosticsList<'a>)> {
+		let attempts = self.attempts.borrow();
+		let attempt = attempts.get(&key(input, memoization))?;
+		let parsed = match &attempt.parsed {
+			// SAFETY: Only `T`s are memoized under `memoization`'s marker, as promised to `Memoization::new`.
+			Some(parsed) => (memoization.clone)(unsafe { parsed.value.cast::<T>().as_ref() }),
+			None => (memoization.placeholder)(),
+		};
+		Some((parsed, attempt.consumed, attempt.diagnostics.clone()))
+	}
+
+	/// Memoizes an attempt to parse a `T` at `input`'s cursor, with what was parsed if it succeeded.
+	pub(crate) fn record<T>(
+		&self,
+		input: &Input<'a>,
+		memoization: &Memoization<T>,
+		consumed: usize,
+		diagnostics: DiagnosticsList<'a>,
+		parsed: Option<&T>,
+	) {
+		self.attempts
+			.borrow_mut()
+			.entry(key(input, memoization))
+			.or_insert_with(|| Attempt {
+				consumed,
+				diagnostics,
+				parsed: parsed.map(|parsed| Stored::new((memoization.clone)(parsed))),
+			});
+	}
+}
+
+impl Memo<'_> {
+	/// The last of the postfix operator offsets that `scan` finds in `prefix`,
+	/// which is only scanned if no longer prefix of the same tokens was.
+	pub(crate) fn postfix_start(
+		&self,
+		prefix: &[TokenTree],
+		scan: fn(&[TokenTree]) -> Vec<usize>,
+	) -> Option<usize> {
+		let mut postfix_starts = self.postfix_starts.borrow_mut();
+		let (scanned, starts) = postfix_starts.entry(prefix.as_ptr() as usize).or_default();
+		if *scanned < prefix.len() {
+			*starts = scan(prefix);
+			*scanned = prefix.len();
+		}
+		let count = starts.partition_point(|&start| start < prefix.len());
+		count.checked_sub(1).map(|last| starts[last])
+	}
+
+	/// Whether `tokens` are a prefix of tokens that were [found to be one operand](`Memo::record_operand`).
+	///
+	/// Each level of a postfix chain parses its left side from such a prefix.
+	pub(crate) fn is_operand(&self, tokens: &[TokenTree]) -> bool {
+		self.operands
+			.borrow()
+			.get(&(tokens.as_ptr() as usize))
+			.is_some_and(|&len| tokens.len() <= len)
+	}
+
+	/// Remembers that `tokens` are one operand, without infix operators.
+	pub(crate) fn record_operand(&self, tokens: &[TokenTree]) {
+		let mut operands = self.operands.borrow_mut();
+		let len = operands.entry(tokens.as_ptr() as usize).or_default();
+		*len = tokens.len().max(*len);
+	}
+}
+
+fn key<T>(input: &Input, memoization: &Memoization<T>) -> Key {
+	(
+		input.cursor.as_ptr() as usize,
+		input.cursor.len(),
+		memoization.marker,
+		input.edition,
+		input.suspended,
+		input.diagnostics.is_probing(),
+	)
+}
+
+#[cfg(test)]
+mod tests {
+	use std::{cell::Cell, thread};
+
+	use crate::{expressions::Expression, io::PARSE_RUNS, parse_str};
+
+	/// How many times a [`Parse::parse`](`crate::io::Parse::parse`) runs to parse a chain of `links` method calls.
+	fn parse_runs(links: usize) -> usize {
+		let source = format!("a{}", ".b()".repeat(links));
+		// Each link nests a few levels deeper, which takes more than the default stack without optimizations.
+		thread::Builder::new()
+			.stack_size(64 << 20)
+			.spawn(move || {
+				parse_str(&source, |parsing| {
+					let (_, diagnostics): (Expression, _) = parsing.parse();
+					assert!(diagnostics.is_empty(), "{diagnostics:?}");
+				})
+				.unwrap();
+				PARSE_RUNS.with(Cell::get)
+			})
+			.unwrap()
+			.join()
+			.unwrap()
+	}
+
+	#[test]
+	fn postfix_chains_take_linear_work() {
+		let (short, long) = (parse_runs(20), parse_runs(40));
+		assert!(
+			long <= 2 * short,
+			"{short} runs for 20 links, {long} for 40"
+		);
+	}
+}
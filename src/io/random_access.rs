@@ -1,82 +1,267 @@
-use std::borrow::Cow;
+//! A flat token buffer, in which each [`Group`] is directly followed by its contents.
+//!
+//! Positions in token slices are counted in buffer slots, so a group with its contents spans [`TokenTree::flat_len`] of them.
+//! Use [`trees`] to step over a slice's top-level token trees.
 
-use proc_macro2::{extra::DelimSpan, Delimiter, Ident, Literal, Punct};
+use std::{mem, ops::Range};
 
-use crate::help::DiagnosticsList;
+use proc_macro2::{extra::DelimSpan, Delimiter, Ident, Literal, Punct, Spacing, Span, TokenStream};
 
-use super::Input;
-
-/// Copy of [`proc_macro2::TokenTree`], except using [`Group`].
-pub enum TokenTree<'a> {
-	Group(Box<Group<'a>>),
+/// Copy of [`proc_macro2::TokenTree`], except that [`Group`]s are followed by their contents.
+#[derive(Debug)]
+pub enum TokenTree {
+	/// Followed by its contents.
+	Group(Group),
+	#[allow(missing_docs)]
 	Ident(Ident),
+	#[allow(missing_docs)]
 	Punct(Punct),
+	#[allow(missing_docs)]
 	Literal(Literal),
 }
 
-impl TokenTree<'_> {
+/// Copy of [`proc_macro2::Group`], except that the contents follow it in the buffer.
+#[derive(Debug)]
+pub struct Group {
+	#[allow(missing_docs)]
+	pub delimiter: Delimiter,
+	#[allow(missing_docs)]
+	pub delim_span: DelimSpan,
+	/// How many slots the contents span.
+	len: usize,
+	/// How many slots the run of empty invisible groups starting here spans, up to the end of the surrounding group.
+	///
+	/// Zero iff this group isn't empty and invisible.
+	empty_run: usize,
+}
+
+impl TokenTree {
+	/// How many slots this token tree spans, including any group contents.
+	#[must_use]
+	pub fn flat_len(&self) -> usize {
+		match self {
+			Self::Group(group) => 1 + group.len,
+			Self::Ident(_) | Self::Punct(_) | Self::Literal(_) => 1,
+		}
+	}
+
+	/// The span of this token tree, including both delimiters of a group.
+	#[must_use]
+	pub fn span(&self) -> Span {
+		match self {
+			Self::Group(group) => group.delim_span.join(),
+			Self::Ident(ident) => ident.span(),
+			Self::Punct(punct) => punct.span(),
+			Self::Literal(literal) => literal.span(),
+		}
+	}
+
+	/// Whether this is an invisible group that contains only more such groups.
 	pub(crate) fn is_empty(&self) -> bool {
+		self.empty_run() > 0
+	}
+
+	/// How many slots the run of empty invisible groups starting here spans.
+	pub(crate) fn empty_run(&self) -> usize {
 		match self {
-			Self::Group(g) => {
-				g.delimiter == Delimiter::None
-					&& Input {
-						cursor: &*g.contents,
-						diagnostics: &DiagnosticsList::default(),
-					}
-					.is_end()
-			}
-			Self::Ident(_) | Self::Punct(_) | Self::Literal(_) => false,
+			Self::Group(group) => group.empty_run,
+			Self::Ident(_) | Self::Punct(_) | Self::Literal(_) => 0,
 		}
 	}
 }
 
-impl From<proc_macro2::TokenTree> for TokenTree<'_> {
-	fn from(value: proc_macro2::TokenTree) -> Self {
-		match value {
-			proc_macro2::TokenTree::Group(group) => Self::Group(group.into()),
-			proc_macro2::TokenTree::Ident(ident) => Self::Ident(ident),
-			proc_macro2::TokenTree::Punct(punct) => Self::Punct(punct),
-			proc_macro2::TokenTree::Literal(literal) => Self::Literal(literal),
+/// Flattens `stream` into a new buffer.
+#[must_use]
+pub fn flatten(stream: TokenStream) -> Vec<TokenTree> {
+	let mut buffer = Vec::new();
+	push_flattened(&mut buffer, stream);
+	buffer
+}
+
+fn push_flattened(buffer: &mut Vec<TokenTree>, stream: TokenStream) {
+	let start = buffer.len();
+	// Whether the previous token tree on this level is a `.` before a field, rather than the end of `..` or `...`.
+	let mut field_dot = false;
+	let mut joint_dot = false;
+	for token_tree in stream {
+		let (is_dot, is_joint) = match &token_tree {
+			proc_macro2::TokenTree::Punct(punct) if punct.as_char() == '.' => {
+				(true, punct.spacing() == Spacing::Joint)
+			}
+			_ => (false, false),
+		};
+		let after_field_dot = mem::replace(&mut field_dot, is_dot && !is_joint && !joint_dot);
+		joint_dot = is_dot && is_joint;
+		match token_tree {
+			proc_macro2::TokenTree::Group(group) => {
+				let index = buffer.len();
+				buffer.push(TokenTree::Group(Group {
+					delimiter: group.delimiter(),
+					delim_span: group.delim_span(),
+					len: 0,
+					empty_run: 0,
+				}));
+				push_flattened(buffer, group.stream());
+				let len = buffer.len() - index - 1;
+				let is_empty = group.delimiter() == Delimiter::None && is_end(&buffer[index + 1..]);
+				if let TokenTree::Group(group) = &mut buffer[index] {
+					group.len = len;
+					// Marks the group as empty until its run is measured below.
+					group.empty_run = usize::from(is_empty);
+				}
+			}
+			proc_macro2::TokenTree::Ident(ident) => buffer.push(TokenTree::Ident(ident)),
+			proc_macro2::TokenTree::Punct(punct) => buffer.push(TokenTree::Punct(punct)),
+			proc_macro2::TokenTree::Literal(literal) if after_field_dot => {
+				push_tuple_indices(buffer, literal);
+			}
+			proc_macro2::TokenTree::Literal(literal) => buffer.push(TokenTree::Literal(literal)),
 		}
 	}
-}
 
-impl From<TokenTree<'_>> for proc_macro2::TokenTree {
-	fn from(value: TokenTree) -> Self {
-		match value {
-			TokenTree::Group(group) => proc_macro2::TokenTree::Group(group.into()),
-			TokenTree::Ident(ident) => proc_macro2::TokenTree::Ident(ident),
-			TokenTree::Punct(punct) => proc_macro2::TokenTree::Punct(punct),
-			TokenTree::Literal(literal) => proc_macro2::TokenTree::Literal(literal),
+	// Measures each run of empty invisible groups on this level, so that checking for the end is O(1).
+	let level = &mut buffer[start..];
+	let mut run_start = None;
+	let mut offset = 0;
+	while offset <= level.len() {
+		let is_empty = level.get(offset).is_some_and(TokenTree::is_empty);
+		match (run_start, is_empty) {
+			(None, true) => run_start = Some(offset),
+			(Some(start), false) => {
+				let mut i = start;
+				while i < offset {
+					let flat_len = level[i].flat_len();
+					if let TokenTree::Group(group) = &mut level[i] {
+						group.empty_run = offset - i;
+					}
+					i += flat_len;
+				}
+				run_start = None;
+			}
+			_ => (),
 		}
+		offset += level.get(offset).map_or(1, TokenTree::flat_len);
 	}
 }
 
-/// Copy of [`Group`], except the contents are a slice.
-pub struct Group<'a> {
-	pub delimiter: Delimiter,
-	pub delim_span: DelimSpan,
-	pub contents: Cow<'a, [TokenTree<'a>]>,
+/// Pushes a literal after a field's `.`, splitting a float like the `0.1` in `a.0.1` into the tuple indices `0` `.` `1` it stands for, like rustc.
+fn push_tuple_indices(buffer: &mut Vec<TokenTree>, literal: Literal) {
+	let text = literal.to_string();
+	let Some((first, second)) = text.split_once('.').filter(|(first, second)| {
+		[first, second]
+			.iter()
+			.all(|index| !index.is_empty() && index.bytes().all(|b| b.is_ascii_digit()))
+	}) else {
+		buffer.push(TokenTree::Literal(literal));
+		return;
+	};
+
+	let span = |range: Range<usize>| literal.subspan(range).unwrap_or_else(|| literal.span());
+	let dot = first.len();
+	let mut first_index: Literal = first.parse().expect("digits");
+	first_index.set_span(span(0..dot));
+	let mut punct = Punct::new('.', Spacing::Alone);
+	punct.set_span(span(dot..dot + 1));
+	let mut second_index: Literal = second.parse().expect("digits");
+	second_index.set_span(span(dot + 1..text.len()));
+	buffer.extend([
+		TokenTree::Literal(first_index),
+		TokenTree::Punct(punct),
+		TokenTree::Literal(second_index),
+	]);
 }
 
-impl From<proc_macro2::Group> for Group<'_> {
-	fn from(value: proc_macro2::Group) -> Self {
-		Self {
-			delimiter: value.delimiter(),
-			delim_span: value.delim_span(),
-			contents: value.stream().into_iter().map(Into::into).collect(),
-		}
+/// Whether `tokens` contains only empty invisible groups, in O(1).
+#[must_use]
+pub fn is_end(tokens: &[TokenTree]) -> bool {
+	tokens
+		.first()
+		.map_or(true, |first| first.empty_run() >= tokens.len())
+}
+
+/// The contents of the group at the start of `tokens`, if there is one.
+#[must_use]
+pub fn group_contents(tokens: &[TokenTree]) -> Option<&[TokenTree]> {
+	match tokens.first() {
+		Some(TokenTree::Group(group)) => Some(&tokens[1..=group.len]),
+		_ => None,
 	}
 }
 
-impl From<Group<'_>> for proc_macro2::Group {
-	fn from(value: Group) -> Self {
-		let mut group =
-			proc_macro2::Group::new(value.delimiter, value.contents.into_iter().collect());
+/// Whether the group at the start of `tokens` directly contains a [`Punct`] `ch`, outside of nested groups.
+///
+/// This tells apart alternatives that share a delimiter, like tuples and parenthesized expressions,
+/// without parsing the group's contents.
+#[must_use]
+pub fn group_contains_punct(tokens: &[TokenTree], ch: char) -> bool {
+	group_contents(tokens).is_some_and(|contents| {
+		trees(contents).any(
+			|(_, token_tree)| matches!(token_tree, TokenTree::Punct(punct) if punct.as_char() == ch),
+		)
+	})
+}
 
-		// There seems to be no way to set the delimiter spans separately.
-		group.set_span(value.delim_span.join());
+/// The span covering all of `tokens`, or of the first one where spans can't be joined.
+#[must_use]
+pub fn span(tokens: &[TokenTree]) -> Option<Span> {
+	let (_, last) = trees(tokens).last()?;
+	let first = tokens[0].span();
+	Some(first.join(last.span()).unwrap_or(first))
+}
 
-		group
+/// Returns the index after the `>` matching the `<` at `start`.
+pub(crate) fn skip_angle_brackets(tokens: &[TokenTree], start: usize) -> usize {
+	let mut depth = 0_usize;
+	let mut previous = None::<&TokenTree>;
+	for (offset, token_tree) in trees(&tokens[start..]) {
+		if let TokenTree::Punct(punct) = token_tree {
+			if punct.as_char() == '<' {
+				depth += 1;
+			} else if punct.as_char() == '>'
+				// `->` and `=>` don't close anything.
+				&& !matches!(previous, Some(TokenTree::Punct(previous)) if matches!(previous.as_char(), '-' | '=') && previous.spacing() == Spacing::Joint)
+			{
+				depth -= 1;
+				if depth == 0 {
+					return start + offset + 1;
+				}
+			}
+		}
+		previous = Some(token_tree);
 	}
+	tokens.len()
+}
+
+/// Iterates over the top-level token trees in `tokens`, along with their offsets.
+pub fn trees(tokens: &[TokenTree]) -> impl Iterator<Item = (usize, &TokenTree)> {
+	let mut offset = 0;
+	std::iter::from_fn(move || {
+		let token_tree = tokens.get(offset)?;
+		let item = (offset, token_tree);
+		offset += token_tree.flat_len();
+		Some(item)
+	})
+}
+
+/// Converts `tokens` back into a [`TokenStream`].
+#[must_use]
+pub fn to_token_stream(tokens: &[TokenTree]) -> TokenStream {
+	trees(tokens)
+		.map(|(offset, token_tree)| -> proc_macro2::TokenTree {
+			match token_tree {
+				TokenTree::Group(group) => {
+					let mut new = proc_macro2::Group::new(
+						group.delimiter,
+						to_token_stream(&tokens[offset + 1..=offset + group.len]),
+					);
+					// There seems to be no way to set the delimiter spans separately.
+					new.set_span(group.delim_span.join());
+					new.into()
+				}
+				TokenTree::Ident(ident) => ident.clone().into(),
+				TokenTree::Punct(punct) => punct.clone().into(),
+				TokenTree::Literal(literal) => literal.clone().into(),
+			}
+		})
+		.collect()
 }
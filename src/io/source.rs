@@ -0,0 +1,97 @@
+//! Entry points for parsing source text, rather than procedural macro input.
+
+use std::{fs, io, path::Path, str::FromStr};
+
+use proc_macro2::{LexError, TokenStream};
+
+use crate::help::DiagnosticsList;
+
+use super::{
+	arena::Arena,
+	edition::Edition,
+	memo::Memo,
+	random_access::{self, TokenTree},
+	Input, Parse,
+};
+
+/// Tokenized source text, which parsed nodes borrow from.
+pub struct Source {
+	tokens: Vec<TokenTree>,
+	edition: Edition,
+}
+
+impl Source {
+	/// Reads and tokenizes the file at `path`.
+	///
+	/// # Errors
+	///
+	/// Iff the file can't be read or isn't valid Rust tokens.
+	pub fn read(path: impl AsRef<Path>) -> io::Result<Self> {
+		fs::read_to_string(path)?
+			.parse()
+			.map_err(|error: LexError| {
+				io::Error::new(io::ErrorKind::InvalidData, error.to_string())
+			})
+	}
+
+	/// Parses as `edition` instead of the [default](`Edition::default`) one.
+	#[must_use]
+	pub fn with_edition(mut self, edition: Edition) -> Self {
+		self.edition = edition;
+		self
+	}
+
+	/// The lexed tokens.
+	#[must_use]
+	pub fn tokens(&self) -> &[TokenTree] {
+		&self.tokens
+	}
+
+	/// The edition to parse as.
+	#[must_use]
+	pub fn edition(&self) -> Edition {
+		self.edition
+	}
+
+	/// Parses a `T` from all tokens, reporting any that are left over.
+	///
+	/// Pass a `memo` to not reparse what nested alternatives already parsed, which can otherwise take exponential time.
+	/// Pass an `arena` to allocate [`Node`](`super::arena::Node`)s in it instead of boxing them.
+	/// Follow-on errors are [pruned](`DiagnosticsList::prune`) afterwards.
+	pub fn parse<'a, T: Parse<'a>>(
+		&'a self,
+		diagnostics: &'a DiagnosticsList<'a>,
+		memo: Option<&'a Memo<'a>>,
+		arena: Option<&'a Arena>,
+	) -> T {
+		let input = Input {
+			cursor: &[],
+			previous: None,
+			diagnostics,
+			memo,
+			arena,
+			suspended: false,
+			edition: self.edition,
+		};
+		let parsed = input.parse_exhaustively(&self.tokens);
+		diagnostics.prune();
+		parsed
+	}
+}
+
+impl From<TokenStream> for Source {
+	fn from(value: TokenStream) -> Self {
+		Self {
+			tokens: random_access::flatten(value),
+			edition: Edition::default(),
+		}
+	}
+}
+
+impl FromStr for Source {
+	type Err = LexError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		s.parse::<TokenStream>().map(Into::into)
+	}
+}
@@ -1,18 +1,26 @@
-use std::fmt::Write;
+//! [Items](https://doc.rust-lang.org/stable/reference/items.html).
+
+use std::fmt::{self, Write};
 
 use either::Either;
 
 use crate::{
 	attributes::OuterAttribute,
-	io::{Input, Parse},
+	io::{first::First, Input, Parse},
+	macros::{MacroInvocationSemi, MacroRulesDefinition},
 	names::visibility_and_privacy::Visibility,
 };
 
+use self::modules::Module;
+
 pub mod modules;
 
+/// An item, like a module or macro invocation, with its outer attributes.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub struct Item<'a> {
-	outer_attributes: Vec<OuterAttribute<'a>>,
-	variant: Either<VisItem<'a>, MacroItem<'a>>,
+	pub outer_attributes: Vec<OuterAttribute<'a>>,
+	pub variant: Either<VisItem<'a>, MacroItem<'a>>,
 }
 
 impl<'a> Parse<'a> for Item<'a> {
@@ -23,14 +31,32 @@ impl<'a> Parse<'a> for Item<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		<(Vec<OuterAttribute>, Either<VisItem, MacroItem>)>::describe(w)
 	}
+
+	fn first() -> First {
+		OuterAttribute::first()
+			.or(VisItem::first())
+			.or(MacroItem::first())
+	}
+}
+
+impl Default for Item<'_> {
+	fn default() -> Self {
+		Self {
+			outer_attributes: Vec::new(),
+			variant: Either::Left(VisItem::default()),
+		}
+	}
 }
 
+/// An item that can have a [`Visibility`].
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct VisItem<'a> {
-	visibility: Option<Visibility<'a>>,
-	variant: VisItemVariant<'a>,
+	pub visibility: Option<Visibility<'a>>,
+	pub variant: VisItemVariant<'a>,
 }
 
 impl<'a> Parse<'a> for VisItem<'a> {
@@ -41,28 +67,79 @@ impl<'a> Parse<'a> for VisItem<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		<(Option<Visibility>, VisItemVariant)>::describe(w)
 	}
+
+	fn first() -> First {
+		Visibility::first().or(VisItemVariant::first())
+	}
+}
+
+/// The kinds of [`VisItem`].
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub enum VisItemVariant<'a> {
+	Module(Module<'a>),
+	//TODO: The other items.
+}
+
+impl<'a> Parse<'a> for VisItemVariant<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		if let Some(module) = input.parse() {
+			Self::Module(module)
+		} else {
+			input.error_expected()
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Module::describe(w)
+	}
+
+	fn first() -> First {
+		Module::first()
+	}
 }
 
+impl Default for VisItemVariant<'_> {
+	fn default() -> Self {
+		Self::Module(Module::default())
+	}
+}
+
+/// A macro invocation or `macro_rules!` definition as item.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub enum MacroItem<'a> {
 	MacroInvocationSemi(MacroInvocationSemi<'a>),
 	MacroRulesDefinition(MacroRulesDefinition<'a>),
 }
 
 impl<'a> Parse<'a> for MacroItem<'a> {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut Input<'a>) -> Self {
-		if let (mis, Ok(())) = input.try_parse() {
+		// `macro_rules!` comes first, since it would also parse as macro invocation.
+		if let Some(mrd) = input.parse() {
+			Self::MacroRulesDefinition(mrd)
+		} else if let Some(mis) = input.parse() {
 			Self::MacroInvocationSemi(mis)
-		} else if let (mrd, Ok(())) = input.try_parse() {
-			Self::MacroRulesDefinition(mis)
 		} else {
 			input.error_expected()
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		Either::<MacroInvocationSemi, MacroRulesDefinition>::describe(w)
 	}
+
+	fn first() -> First {
+		MacroInvocationSemi::first().or(MacroRulesDefinition::first())
+	}
+}
+
+impl Default for MacroItem<'_> {
+	fn default() -> Self {
+		Self::MacroInvocationSemi(MacroInvocationSemi::default())
+	}
 }
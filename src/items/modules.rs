@@ -1,11 +1,13 @@
-use std::fmt::Write;
+//! [Modules](https://doc.rust-lang.org/stable/reference/items/modules.html).
+
+use std::fmt::{self, Write};
 
 use either::Either;
 
 use crate::{
 	attributes::InnerAttribute,
 	identifiers::Identifier,
-	io::{Input, Parse},
+	io::{first::First, Input, Parse},
 	tokens::{
 		delimiters::Braces,
 		keywords::{Mod, Unsafe},
@@ -15,11 +17,15 @@ use crate::{
 
 use super::Item;
 
+/// `mod name;` or `mod name { … }`, optionally `unsafe`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub struct Module<'a> {
-	r#unsafe: Option<Unsafe>,
-	r#mod: Mod,
-	identifier: Identifier,
-	semi_or_braces: Either<Semi, Braces<'a, (Vec<InnerAttribute<'a>>, Vec<Item<'a>>)>>,
+	pub r#unsafe: Option<Unsafe>,
+	pub r#mod: Mod,
+	pub identifier: Identifier,
+	#[allow(clippy::type_complexity)]
+	pub semi_or_braces: Either<Semi, Braces<'a, (Vec<InnerAttribute<'a>>, Vec<Item<'a>>)>>,
 }
 
 impl<'a> Parse<'a> for Module<'a> {
@@ -32,7 +38,7 @@ impl<'a> Parse<'a> for Module<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		<(
 			Option<Unsafe>,
 			Mod,
@@ -40,6 +46,10 @@ impl<'a> Parse<'a> for Module<'a> {
 			Either<Semi, Braces<(Vec<InnerAttribute>, Vec<Item>)>>,
 		)>::describe(w)
 	}
+
+	fn first() -> First {
+		Unsafe::first().or(Mod::first())
+	}
 }
 
 impl Default for Module<'_> {
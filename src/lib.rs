@@ -8,6 +8,9 @@
 #![warn(clippy::pedantic, missing_docs)]
 #![allow(clippy::semicolon_if_nothing_returned)]
 
+#[cfg(feature = "proc-macro")]
+extern crate proc_macro;
+
 #[cfg(doctest)]
 #[doc = include_str!("../README.md")]
 mod readme {}
@@ -25,6 +28,148 @@ pub mod statements;
 pub mod tokens;
 pub mod type_system;
 
+use std::path::Path;
+
+use proc_macro2::{LexError, TokenStream};
+
+use crate::{
+	help::DiagnosticsList,
+	io::{arena::Arena, edition::Edition, memo::Memo, source::Source, Parse},
+};
+
+/// Tokens to parse from, which the parsed nodes borrow for `'a`.
+///
+/// The entry points below pass one to a closure, so that nothing that borrows from the tokens outlives them.
+#[derive(Clone, Copy)]
+pub struct Parsing<'a> {
+	source: &'a Source,
+	diagnostics: &'a DiagnosticsList<'a>,
+	memo: &'a Memo<'a>,
+	arena: &'a Arena,
+	in_arena: bool,
+}
+
+impl<'a> Parsing<'a> {
+	/// Parses a `T` from all of the tokens, returning it along with its diagnostics.
+	///
+	/// Since the result is complete even if there were errors, expand to what can be built from it
+	/// and append [`DiagnosticsList::to_compile_errors`].
+	#[must_use]
+	pub fn parse<T: Parse<'a>>(self) -> (T, DiagnosticsList<'a>) {
+		let arena = self.in_arena.then_some(self.arena);
+		let parsed = self.source.parse(self.diagnostics, Some(self.memo), arena);
+		(parsed, self.diagnostics.split_off(0))
+	}
+
+	/// Allocates [`Node`](`io::arena::Node`)s in [an arena](`Parsing::arena`) instead of boxing them one by one.
+	///
+	/// The arena is freed along with the tokens, once the entry point returns.
+	#[must_use]
+	pub fn in_arena(self) -> Self {
+		Self {
+			in_arena: true,
+			..self
+		}
+	}
+
+	/// The arena that [`Parsing::in_arena`] allocates in.
+	#[must_use]
+	pub fn arena(self) -> &'a Arena {
+		self.arena
+	}
+
+	/// The tokens and edition.
+	#[must_use]
+	pub fn source(self) -> &'a Source {
+		self.source
+	}
+}
+
+/// Calls `f` to parse from all of `tokens`, returning its result.
+///
+/// This is the standard way to use loess in a procedural macro:
+///
+/// ```
+/// use loess::expressions::Expression;
+/// use proc_macro2::TokenStream;
+///
+/// let tokens: TokenStream = "1 + 2".parse().unwrap();
+/// let errors = loess::parse2(tokens, |parsing| {
+///     let (_expression, diagnostics): (Expression, _) = parsing.parse();
+///     diagnostics.to_compile_errors()
+/// });
+/// assert!(errors.is_empty());
+/// ```
+pub fn parse2<R>(tokens: TokenStream, f: impl for<'a> FnOnce(Parsing<'a>) -> R) -> R {
+	parse_source(&tokens.into(), f)
+}
+
+/// Like [`parse2`], but parses as `edition` instead of the [default](`Edition::default`) one.
+///
+/// Pass the edition of the crate that invokes the macro, so that keywords and edition-dependent syntax are handled like there.
+pub fn parse2_in<R>(
+	tokens: TokenStream,
+	edition: Edition,
+	f: impl for<'a> FnOnce(Parsing<'a>) -> R,
+) -> R {
+	parse_source(&Source::from(tokens).with_edition(edition), f)
+}
+
+/// Calls `f` to parse from all of `tokens`, returning its result.
+///
+/// See [`parse2`].
+#[cfg(feature = "proc-macro")]
+pub fn parse<R>(tokens: proc_macro::TokenStream, f: impl for<'a> FnOnce(Parsing<'a>) -> R) -> R {
+	parse2(tokens.into(), f)
+}
+
+/// Like [`parse`], but parses as `edition`.
+///
+/// See [`parse2_in`].
+#[cfg(feature = "proc-macro")]
+pub fn parse_in<R>(
+	tokens: proc_macro::TokenStream,
+	edition: Edition,
+	f: impl for<'a> FnOnce(Parsing<'a>) -> R,
+) -> R {
+	parse2_in(tokens.into(), edition, f)
+}
+
+/// Calls `f` to parse from all of `source`, returning its result.
+///
+/// # Errors
+///
+/// Iff `source` isn't valid Rust tokens.
+pub fn parse_str<R>(source: &str, f: impl for<'a> FnOnce(Parsing<'a>) -> R) -> Result<R, LexError> {
+	Ok(parse_source(&source.parse()?, f))
+}
+
+/// Calls `f` to parse from all of the file at `path`, returning its result.
+///
+/// # Errors
+///
+/// Iff the file can't be read or isn't valid Rust tokens.
+pub fn parse_file<R>(
+	path: impl AsRef<Path>,
+	f: impl for<'a> FnOnce(Parsing<'a>) -> R,
+) -> std::io::Result<R> {
+	Ok(parse_source(&Source::read(path)?, f))
+}
+
+fn parse_source<R>(source: &Source, f: impl for<'a> FnOnce(Parsing<'a>) -> R) -> R {
+	let diagnostics = DiagnosticsList::default();
+	let memo = Memo::default();
+	let arena = Arena::new();
+	f(Parsing {
+		source,
+		diagnostics: &diagnostics,
+		memo: &memo,
+		arena: &arena,
+		in_arena: false,
+	})
+}
+
+/// The punctuation type for a token, like `Token![+]` for [`Plus`](`tokens::punctuation::Plus`).
 #[macro_export]
 macro_rules! Token {
 	[+] => { $crate::tokens::punctuation::Plus };
@@ -45,7 +190,7 @@ macro_rules! Token {
 	[*=] => { $crate::tokens::punctuation::StarEq };
 	[/=] => { $crate::tokens::punctuation::SlashEq };
 	[%=] => { $crate::tokens::punctuation::PercentEq };
-	[^=] => { $crate::tokens::punctuation::CareEq };
+	[^=] => { $crate::tokens::punctuation::CaretEq };
 	[&=] => { $crate::tokens::punctuation::AndEq };
 	[|=] => { $crate::tokens::punctuation::OrEq };
 	[<<=] => { $crate::tokens::punctuation::ShlEq };
@@ -1,14 +1,21 @@
-use std::fmt::Write;
+//! [Macro invocations](https://doc.rust-lang.org/stable/reference/macros.html).
+
+use std::fmt::{self, Write};
 
 use crate::{
-	io::{random_access::TokenTree, Input, Parse},
+	help::{catalog::Message, Expected},
+	identifiers::Identifier,
+	io::{first::First, random_access::TokenTree, Input, Parse},
 	names::paths::SimplePath,
 	tokens::{
 		delimiters::{Braces, Brackets, Parentheses},
-		punctuation::Not,
+		punctuation::{Not, Semi},
 	},
 };
 
+/// `path!(…)`, `path![…]` or `path!{…}` as expression, pattern or type.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct MacroInvocation<'a> {
 	pub simple_path: SimplePath,
 	pub not: Not,
@@ -24,47 +31,165 @@ impl<'a> Parse<'a> for MacroInvocation<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		<(SimplePath, Not, DelimTokenTree)>::describe(w)
 	}
-}
 
-impl Default for MacroInvocation<'_> {
-	fn default() -> Self {
-		Self {
-			simple_path: Default::default(),
-			not: Default::default(),
-			delim_token_tree: Default::default(),
-		}
+	fn first() -> First {
+		SimplePath::first()
 	}
 }
 
+/// Tokens in parentheses, brackets or braces, as passed to a macro.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub enum DelimTokenTree<'a> {
-	Parentheses(Parentheses<'a, Vec<TokenTree<'a>>>),
-	Brackets(Brackets<'a, Vec<TokenTree<'a>>>),
-	Braces(Braces<'a, Vec<TokenTree<'a>>>),
+	Parentheses(Parentheses<'a, TokenTrees<'a>>),
+	Brackets(Brackets<'a, TokenTrees<'a>>),
+	Braces(Braces<'a, TokenTrees<'a>>),
 }
 
 impl<'a> Parse<'a> for DelimTokenTree<'a> {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut Input<'a>) -> Self {
-		if let (parentheses, Ok(())) = input.try_parse() {
+		if let Some(parentheses) = input.parse() {
 			Self::Parentheses(parentheses)
-		} else if let (brackets, Ok(())) = input.try_parse() {
+		} else if let Some(brackets) = input.parse() {
 			Self::Brackets(brackets)
-		} else if let (braces, Ok(())) = input.try_parse() {
+		} else if let Some(braces) = input.parse() {
 			Self::Braces(braces)
 		} else {
 			input.error_expected()
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		w.write_char('(')?;
-		Parentheses::<Vec<TokenTree>>::describe(w)?;
+		Parentheses::<TokenTrees>::describe(w)?;
 		w.write_char('|')?;
-		Brackets::<Vec<TokenTree>>::describe(w)?;
+		Brackets::<TokenTrees>::describe(w)?;
 		w.write_char('|')?;
-		Braces::<Vec<TokenTree>>::describe(w)?;
+		Braces::<TokenTrees>::describe(w)?;
 		w.write_char(')')
 	}
+
+	fn first() -> First {
+		Parentheses::<TokenTrees>::first()
+			.or(Brackets::<TokenTrees>::first())
+			.or(Braces::<TokenTrees>::first())
+	}
+}
+
+impl Default for DelimTokenTree<'_> {
+	fn default() -> Self {
+		Self::Parentheses(Parentheses::default())
+	}
+}
+
+/// All remaining tokens, unparsed.
+#[derive(Debug, Clone, Copy, Default)]
+#[allow(missing_docs)]
+pub struct TokenTrees<'a> {
+	pub tokens: &'a [TokenTree],
+}
+
+impl<'a> Parse<'a> for TokenTrees<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			tokens: input.advance(input.cursor.len()),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-any-times")
+			.arg("item", Expected::new(|w| w.write_str("TOKEN_TREE")))
+			.write(w)
+	}
+
+	fn looks_through_invisible_groups() -> bool {
+		false
+	}
+}
+
+/// A macro invocation as statement or item, which needs a `;` unless braced.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct MacroInvocationSemi<'a> {
+	pub simple_path: SimplePath,
+	pub not: Not,
+	pub delim_token_tree: DelimTokenTree<'a>,
+	/// [`None`] iff the [`DelimTokenTree`] is [braced](`DelimTokenTree::Braces`).
+	pub semi: Option<Semi>,
+}
+
+impl<'a> Parse<'a> for MacroInvocationSemi<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		let simple_path = input.parse();
+		let not = input.parse();
+		let delim_token_tree = input.parse();
+		let semi = match delim_token_tree {
+			DelimTokenTree::Braces(_) => None,
+			DelimTokenTree::Parentheses(_) | DelimTokenTree::Brackets(_) => Some(input.parse()),
+		};
+		Self {
+			simple_path,
+			not,
+			delim_token_tree,
+			semi,
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(SimplePath, Not, DelimTokenTree, Option<Semi>)>::describe(w)
+	}
+
+	fn first() -> First {
+		SimplePath::first()
+	}
+}
+
+/// `macro_rules! name { … }`.
+///
+/// Only the name is checked, the rules are kept as tokens.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct MacroRulesDefinition<'a> {
+	/// Always `macro_rules`, which is not a keyword.
+	pub macro_rules: Identifier,
+	pub not: Not,
+	pub identifier: Identifier,
+	pub delim_token_tree: DelimTokenTree<'a>,
+	/// [`None`] iff the [`DelimTokenTree`] is [braced](`DelimTokenTree::Braces`).
+	pub semi: Option<Semi>,
+}
+
+impl<'a> Parse<'a> for MacroRulesDefinition<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		let macro_rules: Identifier = match input.cursor.first() {
+			Some(TokenTree::Ident(ident)) if ident == "macro_rules" => input.parse(),
+			_ => return input.error_expected(),
+		};
+		let not = input.parse();
+		let identifier = input.parse();
+		let delim_token_tree = input.parse();
+		let semi = match delim_token_tree {
+			DelimTokenTree::Braces(_) => None,
+			DelimTokenTree::Parentheses(_) | DelimTokenTree::Brackets(_) => Some(input.parse()),
+		};
+		Self {
+			macro_rules,
+			not,
+			identifier,
+			delim_token_tree,
+			semi,
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		w.write_str("`macro_rules! … { … }`")
+	}
+
+	fn first() -> First {
+		First::keyword("macro_rules")
+	}
 }
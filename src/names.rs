@@ -1,2 +1,4 @@
+//! [Names](https://doc.rust-lang.org/stable/reference/names.html).
+
 pub mod paths;
 pub mod visibility_and_privacy;
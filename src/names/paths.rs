@@ -1,23 +1,33 @@
-use std::fmt::Write;
+//! [Paths](https://doc.rust-lang.org/stable/reference/paths.html).
+
+use std::{
+	cell::Cell,
+	fmt::{self, Write},
+};
 
 use either::Either;
 use vec1::Vec1;
 
 use crate::{
 	expressions::{block_expressions::BlockExpression, literal_expressions::LiteralExpression},
+	help::{catalog::Message, Expected},
 	identifiers::Identifier,
-	io::{Input, Parse},
+	io::{arena::Node, first::First, random_access::TokenTree, Input, Parse},
+	macros::TokenTrees,
 	tokens::{
 		delimiters::{Braces, Brackets, Parentheses},
 		keywords::{As, Crate, Selftype, Selfvalue, Super, Where},
 		punctuation::{
-			Colon, ColonColon, Comma, Dollar, Eq, FatArrow, Gt, Lt, Minus, Or, RArrow, Semi, Shr,
+			Colon, ColonColon, Comma, Dollar, Dot, DotDot, DotDotDot, DotDotEq, Eq, FatArrow, Gt,
+			Lt, Minus, Or, Plus, RArrow, Semi, Shr,
 		},
 	},
 	type_system::{trait_and_lifetime_bounds::Lifetime, types::Type},
 };
 
+/// `a::b::c`, optionally starting with `::`, as in attributes and visibilities.
 #[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct SimplePath {
 	pub colon_colon: Option<ColonColon>,
 	pub simple_path_segment: SimplePathSegment,
@@ -33,15 +43,22 @@ impl Parse<'_> for SimplePath {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		<(
 			Option<ColonColon>,
 			SimplePathSegment,
 			Vec<(ColonColon, SimplePathSegment)>,
 		)>::describe(w)
 	}
+
+	fn first() -> First {
+		ColonColon::first().or(SimplePathSegment::first())
+	}
 }
 
+/// An identifier, `super`, `self`, `crate` or `$crate`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub enum SimplePathSegment {
 	Identifier(Identifier),
 	Super(Super),
@@ -51,15 +68,33 @@ pub enum SimplePathSegment {
 }
 
 impl Parse<'_> for SimplePathSegment {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut Input<'_>) -> Self {
-		todo!()
+		// Keywords come first, since `Identifier` would take them with an error.
+		if let Some(super_) = input.parse() {
+			Self::Super(super_)
+		} else if let Some(selfvalue) = input.parse() {
+			Self::Selfvalue(selfvalue)
+		} else if let Some(crate_) = input.parse() {
+			Self::Crate(crate_)
+		} else if let Some((dollar, crate_)) = input.parse() {
+			Self::DollarCrate(dollar, crate_)
+		} else if let Some(identifier) = input.parse() {
+			Self::Identifier(identifier)
+		} else {
+			input.error_expected()
+		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		w.write_char('(')?;
 		Identifier::describe(w)?;
 		w.write_str("|`super`|`self`|`crate`|`$crate)`")
 	}
+
+	fn first() -> First {
+		Identifier::first().or(Dollar::first())
+	}
 }
 
 impl Default for SimplePathSegment {
@@ -68,28 +103,59 @@ impl Default for SimplePathSegment {
 	}
 }
 
+/// A path in an expression or pattern, like `Vec::<u8>::new`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct PathInExpression<'a> {
 	pub colon_colon: Option<ColonColon>,
 	pub path_expr_segment: PathExprSegment<'a>,
 	pub rest: Vec<(ColonColon, PathExprSegment<'a>)>,
 }
 
+/// Whether the next token starts with the [`Punct`](`proc_macro2::Punct`) `ch`, like `>` in `>>`.
+///
+/// Generic arguments open and close one `<` or `>` at a time.
+fn starts_with_punct(input: &Input, ch: char) -> bool {
+	matches!(input.cursor.first(), Some(TokenTree::Punct(punct)) if punct.as_char() == ch)
+}
+
+fn is_path_in_expression_end(input: &Input) -> bool {
+	input.peek::<FatArrow>()
+		|| input.peek::<Comma>()
+		|| input.peek::<Eq>()
+		|| input.peek::<Or>()
+		|| input.peek::<Semi>()
+		|| input.peek::<Colon>()
+		|| input.peek::<Gt>()
+		|| input.peek::<Shr>()
+		|| input.peek::<Brackets<TokenTrees>>()
+		|| input.peek::<Braces<TokenTrees>>()
+		|| input.peek::<Parentheses<TokenTrees>>()
+		|| input.peek::<Dot>()
+		|| input.peek::<DotDot>()
+		|| input.peek::<DotDotDot>()
+		|| input.peek::<DotDotEq>()
+		|| input.peek::<As>()
+		|| input.peek::<Where>()
+}
+
 impl<'a> Parse<'a> for PathInExpression<'a> {
 	fn parse(input: &mut Input<'a>) -> Self {
+		// Generic arguments after `::` may contain terminators, so those are skipped.
+		let generics_depth = Cell::new(0_usize);
+		let after_colon_colon = Cell::new(false);
 		let (colon_colon, path_expr_segment, rest) = input.parse_to(|input| {
-			input.is_end()
-				|| input.peek::<FatArrow>()
-				|| input.peek::<Comma>()
-				|| input.peek::<Eq>()
-				|| input.peek::<Or>()
-				|| input.peek::<Semi>()
-				|| input.peek::<Colon>()
-				|| input.peek::<Gt>()
-				|| input.peek::<Shr>()
-				|| input.peek::<Brackets<_>>()
-				|| input.peek::<Braces<_>>()
-				|| input.peek::<As>()
-				|| input.peek::<Where>()
+			let depth = generics_depth.get();
+			if after_colon_colon.replace(input.peek::<ColonColon>()) || depth > 0 {
+				if starts_with_punct(input, '<') {
+					generics_depth.set(depth + 1);
+					return false;
+				} else if starts_with_punct(input, '>') && depth > 0 {
+					generics_depth.set(depth - 1);
+					return false;
+				}
+			}
+			input.is_end() || (depth == 0 && is_path_in_expression_end(input))
 		});
 		Self {
 			colon_colon,
@@ -98,16 +164,31 @@ impl<'a> Parse<'a> for PathInExpression<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
-		<(
-			Option<ColonColon>,
-			PathExprSegment,
-			Vec<(ColonColon, PathExprSegment)>,
-		)>::describe(w)?;
-		w.write_str(" before (end|`=>`|`>>`|`as`|`where`|[,=|;:>[{])");
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-before")
+			.arg(
+				"item",
+				Expected::of::<(
+					Option<ColonColon>,
+					PathExprSegment,
+					Vec<(ColonColon, PathExprSegment)>,
+				)>(),
+			)
+			.arg(
+				"terminators",
+				"(end|`=>`|`>>`|`.`|`..`|`...`|`..=`|`as`|`where`|[,=|;:>[{(])",
+			)
+			.write(w)
+	}
+
+	fn first() -> First {
+		ColonColon::first().or(PathIdentSegment::first())
 	}
 }
 
+/// A path segment, optionally with `::<…>` generic arguments.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct PathExprSegment<'a> {
 	pub path_ident_segment: PathIdentSegment,
 	pub generics: Option<(ColonColon, GenericArgs<'a>)>,
@@ -120,8 +201,19 @@ impl<'a> Parse<'a> for PathExprSegment<'a> {
 			generics: input.parse(),
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(PathIdentSegment, Option<(ColonColon, GenericArgs)>)>::describe(w)
+	}
+
+	fn first() -> First {
+		PathIdentSegment::first()
+	}
 }
 
+/// An identifier, `super`, `self`, `Self`, `crate` or `$crate`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub enum PathIdentSegment {
 	Identifier(Identifier),
 	Super(Super),
@@ -132,6 +224,7 @@ pub enum PathIdentSegment {
 }
 
 impl Parse<'_> for PathIdentSegment {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut Input<'_>) -> Self {
 		if let (identifier, Ok(())) = input.try_parse() {
 			Self::Identifier(identifier)
@@ -146,11 +239,30 @@ impl Parse<'_> for PathIdentSegment {
 		} else if let ((dollar, crate_), Ok(())) = input.try_parse() {
 			Self::DollarCrate(dollar, crate_)
 		} else {
-			todo!()
+			input.error_expected()
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		w.write_char('(')?;
+		Identifier::describe(w)?;
+		w.write_str("|`super`|`self`|`Self`|`crate`|`$crate`)")
+	}
+
+	fn first() -> First {
+		Identifier::first().or(Dollar::first())
+	}
+}
+
+impl Default for PathIdentSegment {
+	fn default() -> Self {
+		Self::Identifier(Identifier::default())
+	}
 }
 
+/// `<>` or `<A, B>`, with an optional trailing comma.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub enum GenericArgs<'a> {
 	Empty(Lt, Gt),
 	Some(
@@ -158,6 +270,7 @@ pub enum GenericArgs<'a> {
 		GenericArg<'a>,
 		Vec<(Comma, GenericArg<'a>)>,
 		Option<Comma>,
+		Gt,
 	),
 }
 
@@ -165,22 +278,41 @@ impl<'a> Parse<'a> for GenericArgs<'a> {
 	fn parse(input: &mut Input<'a>) -> Self {
 		if let ((lt, gt), Ok(())) = input.try_parse() {
 			Self::Empty(lt, gt)
-		} else if let ((lt, generic_arg, more_generic_args, comma), Ok(())) = input.try_parse() {
-			Self::Some(lt, generic_arg, more_generic_args, comma)
+		} else if let ((lt, generic_arg, more_generic_args, comma, gt), Ok(())) = input.try_parse()
+		{
+			Self::Some(lt, generic_arg, more_generic_args, comma, gt)
 		} else {
-			todo!()
+			input.error_expected()
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		w.write_str("`< … >`")
+	}
+
+	fn first() -> First {
+		Lt::first()
+	}
 }
 
+impl Default for GenericArgs<'_> {
+	fn default() -> Self {
+		Self::Empty(Lt::default(), Gt::default())
+	}
+}
+
+/// A lifetime, type, const argument or associated type binding.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub enum GenericArg<'a> {
 	Lifetime(Lifetime),
-	Type(Type<'a>),
+	Type(Node<'a, Type<'a>>),
 	GenericArgsConst(GenericArgsConst<'a>),
 	GenericArgsBinding(GenericArgsBinding<'a>),
 }
 
 impl<'a> Parse<'a> for GenericArg<'a> {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut Input<'a>) -> Self {
 		if let (lifetime, Ok(())) = input.try_parse() {
 			Self::Lifetime(lifetime)
@@ -191,19 +323,41 @@ impl<'a> Parse<'a> for GenericArg<'a> {
 		} else if let (gab, Ok(())) = input.try_parse() {
 			Self::GenericArgsBinding(gab)
 		} else {
-			todo!()
+			input.error_expected()
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		w.write_char('(')?;
+		Lifetime::describe(w)?;
+		w.write_char('|')?;
+		Type::describe(w)?;
+		w.write_char('|')?;
+		GenericArgsConst::describe(w)?;
+		w.write_char('|')?;
+		GenericArgsBinding::describe(w)?;
+		w.write_char(')')
+	}
+}
+
+impl Default for GenericArg<'_> {
+	fn default() -> Self {
+		Self::Lifetime(Lifetime::default())
+	}
 }
 
+/// A block, a possibly negated literal or a path segment as const generic argument.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub enum GenericArgsConst<'a> {
-	BlockExpression(BlockExpression<'a>),
+	BlockExpression(Node<'a, BlockExpression<'a>>),
 	LiteralExpression(LiteralExpression),
 	MinusLiteralExpression(Minus, LiteralExpression),
 	SimplePathSegment(SimplePathSegment),
 }
 
 impl<'a> Parse<'a> for GenericArgsConst<'a> {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut Input<'a>) -> Self {
 		if let (be, Ok(())) = input.try_parse() {
 			Self::BlockExpression(be)
@@ -214,29 +368,62 @@ impl<'a> Parse<'a> for GenericArgsConst<'a> {
 		} else if let (sps, Ok(())) = input.try_parse() {
 			Self::SimplePathSegment(sps)
 		} else {
-			todo!()
+			input.error_expected()
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		w.write_char('(')?;
+		BlockExpression::describe(w)?;
+		w.write_char('|')?;
+		LiteralExpression::describe(w)?;
+		w.write_char('|')?;
+		<(Minus, LiteralExpression)>::describe(w)?;
+		w.write_char('|')?;
+		SimplePathSegment::describe(w)?;
+		w.write_char(')')
+	}
+}
+
+impl Default for GenericArgsConst<'_> {
+	fn default() -> Self {
+		Self::SimplePathSegment(SimplePathSegment::default())
+	}
 }
 
+/// `Name = Type`, which binds an associated type.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct GenericArgsBinding<'a> {
 	pub idendifier: Identifier,
-	pub r#type: Type<'a>,
+	pub eq: Eq,
+	pub r#type: Node<'a, Type<'a>>,
 }
 
 impl<'a> Parse<'a> for GenericArgsBinding<'a> {
 	fn parse(input: &mut Input<'a>) -> Self {
 		Self {
 			idendifier: input.parse(),
+			eq: input.parse(),
 			r#type: input.parse(),
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Identifier, Eq, Type)>::describe(w)
+	}
+
+	fn first() -> First {
+		Identifier::first()
+	}
 }
 
-#[derive(Default)]
+/// `<T as Trait>::item` in an expression or pattern.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct QualifiedPathInExpression<'a> {
-	qualified_path_type: QualifiedPathType<'a>,
-	rest: Vec1<(ColonColon, PathExprSegment<'a>)>,
+	pub qualified_path_type: QualifiedPathType<'a>,
+	pub rest: Vec1<(ColonColon, PathExprSegment<'a>)>,
 }
 
 impl<'a> Parse<'a> for QualifiedPathInExpression<'a> {
@@ -247,17 +434,23 @@ impl<'a> Parse<'a> for QualifiedPathInExpression<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		<(QualifiedPathType, Vec1<(ColonColon, PathExprSegment)>)>::describe(w)
 	}
+
+	fn first() -> First {
+		QualifiedPathType::first()
+	}
 }
 
-#[derive(Default)]
+/// `<Type>` or `<Type as Trait>`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct QualifiedPathType<'a> {
-	lt: Lt,
-	r#type: Type<'a>,
-	as_type_path: Option<(As, TypePath<'a>)>,
-	gt: Gt,
+	pub lt: Lt,
+	pub r#type: Node<'a, Type<'a>>,
+	pub as_type_path: Option<(As, TypePath<'a>)>,
+	pub gt: Gt,
 }
 
 impl<'a> Parse<'a> for QualifiedPathType<'a> {
@@ -270,15 +463,21 @@ impl<'a> Parse<'a> for QualifiedPathType<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
-		<(Lt, Type<'a>, Option<(As, TypePath<'a>)>, Gt)>::describe(w)
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Lt, Type, Option<(As, TypePath)>, Gt)>::describe(w)
+	}
+
+	fn first() -> First {
+		Lt::first()
 	}
 }
 
-#[derive(Default)]
+/// `<T as Trait>::Item` in a type.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct QualifiedPathInType<'a> {
-	qualified_path_type: QualifiedPathType<'a>,
-	rest: Vec1<(ColonColon, TypePathSegment<'a>)>,
+	pub qualified_path_type: QualifiedPathType<'a>,
+	pub rest: Vec1<(ColonColon, TypePathSegment<'a>)>,
 }
 
 impl<'a> Parse<'a> for QualifiedPathInType<'a> {
@@ -289,34 +488,54 @@ impl<'a> Parse<'a> for QualifiedPathInType<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		<(QualifiedPathType, Vec1<(ColonColon, TypePathSegment)>)>::describe(w)
 	}
+
+	fn first() -> First {
+		QualifiedPathType::first()
+	}
 }
 
-#[derive(Default)]
+/// A path in a type, like `std::vec::Vec<u8>` or `Fn(u8) -> u8`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct TypePath<'a> {
-	colon_colon: Option<ColonColon>,
-	type_path_segment: TypePathSegment<'a>,
-	rest: Vec<(ColonColon, TypePathSegment<'a>)>,
+	pub colon_colon: Option<ColonColon>,
+	pub type_path_segment: TypePathSegment<'a>,
+	pub rest: Vec<(ColonColon, TypePathSegment<'a>)>,
+}
+
+fn is_type_path_end(input: &Input) -> bool {
+	input.peek::<FatArrow>()
+		|| input.peek::<Comma>()
+		|| input.peek::<Eq>()
+		|| input.peek::<Or>()
+		|| input.peek::<Semi>()
+		|| input.peek::<Colon>()
+		|| input.peek::<Gt>()
+		|| input.peek::<Shr>()
+		|| input.peek::<Brackets<TokenTrees>>()
+		|| input.peek::<Braces<TokenTrees>>()
+		|| input.peek::<Plus>()
+		|| input.peek::<As>()
+		|| input.peek::<Where>()
 }
 
 impl<'a> Parse<'a> for TypePath<'a> {
 	fn parse(input: &mut Input<'a>) -> Self {
+		// Generic arguments may contain terminators, so those are skipped.
+		let generics_depth = Cell::new(0_usize);
 		let (colon_colon, type_path_segment, rest) = input.parse_to(|input| {
-			input.is_end()
-				|| input.peek::<FatArrow>()
-				|| input.peek::<Comma>()
-				|| input.peek::<Eq>()
-				|| input.peek::<Or>()
-				|| input.peek::<Semi>()
-				|| input.peek::<Colon>()
-				|| input.peek::<Gt>()
-				|| input.peek::<Shr>()
-				|| input.peek::<Brackets<_>>()
-				|| input.peek::<Braces<_>>()
-				|| input.peek::<As>()
-				|| input.peek::<Where>()
+			let depth = generics_depth.get();
+			if starts_with_punct(input, '<') {
+				generics_depth.set(depth + 1);
+				return false;
+			} else if starts_with_punct(input, '>') && depth > 0 {
+				generics_depth.set(depth - 1);
+				return false;
+			}
+			input.is_end() || (depth == 0 && is_type_path_end(input))
 		});
 		Self {
 			colon_colon,
@@ -325,20 +544,31 @@ impl<'a> Parse<'a> for TypePath<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
-		<(
-			Option<ColonColon>,
-			TypePathSegment,
-			Vec<(ColonColon, TypePathSegment)>,
-		)>::describe(w)?;
-		w.write_str(" before (end|`=>`|`>>`|`as`|`where`|[,=|;:>[{])");
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-before")
+			.arg(
+				"item",
+				Expected::of::<(
+					Option<ColonColon>,
+					TypePathSegment,
+					Vec<(ColonColon, TypePathSegment)>,
+				)>(),
+			)
+			.arg("terminators", "(end|`=>`|`>>`|`as`|`where`|[,=|;:>+[{])")
+			.write(w)
+	}
+
+	fn first() -> First {
+		ColonColon::first().or(PathIdentSegment::first())
 	}
 }
 
-#[derive(Default)]
+/// A type path segment, optionally with generic arguments or [`TypePathFn`] inputs.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct TypePathSegment<'a> {
-	path_ident_segment: PathIdentSegment<'a>,
-	generic_args_or_type_path_fn:
+	pub path_ident_segment: PathIdentSegment,
+	pub generic_args_or_type_path_fn:
 		Option<(Option<ColonColon>, Either<GenericArgs<'a>, TypePathFn<'a>>)>,
 }
 
@@ -350,18 +580,20 @@ impl<'a> Parse<'a> for TypePathSegment<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		<(
-			PathIdentSegment<'a>,
-			Option<(Option<colon_colon>, Either<GenericArgs<'a>, TypePathFn<'a>>)>,
+			PathIdentSegment,
+			Option<(Option<ColonColon>, Either<GenericArgs, TypePathFn>)>,
 		)>::describe(w)
 	}
 }
 
+/// `(A, B) -> C` after a path segment, as in `Fn(A, B) -> C`.
 #[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct TypePathFn<'a> {
-	parentheses: Parentheses<'a, Option<TypePathFnInputs<'a>>>,
-	return_type: Option<(RArrow, Type<'a>)>,
+	pub parentheses: Parentheses<'a, Option<TypePathFnInputs<'a>>>,
+	pub return_type: Option<(RArrow, Node<'a, Type<'a>>)>,
 }
 
 impl<'a> Parse<'a> for TypePathFn<'a> {
@@ -372,19 +604,21 @@ impl<'a> Parse<'a> for TypePathFn<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		<(
-			Parentheses<'a, Option<TypePathFnInputs<'a>>>,
-			Option<(RArrow, Type<'a>)>,
+			Parentheses<Option<TypePathFnInputs>>,
+			Option<(RArrow, Type)>,
 		)>::describe(w)
 	}
 }
 
+/// The comma-separated types between a [`TypePathFn`]'s parentheses.
 #[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct TypePathFnInputs<'a> {
-	first_type: Box<Type<'a>>,
-	rest: Vec<(Comma, Type<'a>)>,
-	comma: Option<Comma>,
+	pub first_type: Node<'a, Type<'a>>,
+	pub rest: Vec<(Comma, Node<'a, Type<'a>>)>,
+	pub comma: Option<Comma>,
 }
 
 impl<'a> Parse<'a> for TypePathFnInputs<'a> {
@@ -396,7 +630,7 @@ impl<'a> Parse<'a> for TypePathFnInputs<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		<(Type, Vec<(Comma, Type)>, Option<Comma>)>::describe(w)
 	}
 }
@@ -1,7 +1,9 @@
-use std::fmt::Write;
+//! [Visibility and privacy](https://doc.rust-lang.org/stable/reference/visibility-and-privacy.html).
+
+use std::fmt::{self, Write};
 
 use crate::{
-	io::{Input, Parse},
+	io::{first::First, Input, Parse},
 	tokens::{
 		delimiters::Parentheses,
 		keywords::{Crate, In, Pub, Selfvalue, Super},
@@ -10,10 +12,12 @@ use crate::{
 
 use super::paths::SimplePath;
 
-#[derive(Default)]
+/// `pub`, optionally restricted like `pub(crate)`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct Visibility<'a> {
-	r#pub: Pub,
-	parentheses: Option<Parentheses<'a, VisibilityVariant>>,
+	pub r#pub: Pub,
+	pub parentheses: Option<Parentheses<'a, VisibilityVariant>>,
 }
 
 impl<'a> Parse<'a> for Visibility<'a> {
@@ -24,11 +28,18 @@ impl<'a> Parse<'a> for Visibility<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
-		<(Pub, Parentheses<VisibilityVariant>)>::describe(w)
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Pub, Option<Parentheses<VisibilityVariant>>)>::describe(w)
+	}
+
+	fn first() -> First {
+		Pub::first()
 	}
 }
 
+/// `crate`, `self`, `super` or `in path` as restriction of a [`Visibility`].
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub enum VisibilityVariant {
 	Crate(Crate),
 	Selfvalue(Selfvalue),
@@ -37,13 +48,14 @@ pub enum VisibilityVariant {
 }
 
 impl Parse<'_> for VisibilityVariant {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut Input<'_>) -> Self {
-		if let (Crate, Ok(())) = input.try_parse() {
-			Self::Crate(Crate)
-		} else if let (Selfvalue, Ok(())) = input.try_parse() {
-			Self::Selfvalue(Selfvalue)
-		} else if let (Super, Ok(())) = input.try_parse() {
-			Self::Super(Super)
+		if let (crate_, Ok(())) = input.try_parse() {
+			Self::Crate(crate_)
+		} else if let (self_value, Ok(())) = input.try_parse() {
+			Self::Selfvalue(self_value)
+		} else if let (super_, Ok(())) = input.try_parse() {
+			Self::Super(super_)
 		} else if let ((r#in, simple_path), Ok(())) = input.try_parse() {
 			Self::InSimplePath(r#in, simple_path)
 		} else {
@@ -51,7 +63,7 @@ impl Parse<'_> for VisibilityVariant {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		w.write_char('(')?;
 		Crate::describe(w)?;
 		w.write_char('|')?;
@@ -59,7 +71,7 @@ impl Parse<'_> for VisibilityVariant {
 		w.write_char('|')?;
 		Super::describe(w)?;
 		w.write_char('|')?;
-		<(In, SimplePath)>::decribe(w)?;
+		<(In, SimplePath)>::describe(w)?;
 		w.write_char(')')
 	}
 }
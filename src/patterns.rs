@@ -1,23 +1,41 @@
-use std::{fmt::Write, marker::PhantomData};
+//! [Patterns](https://doc.rust-lang.org/stable/reference/patterns.html).
 
-use proc_macro2::{Ident, Literal, Span};
+use std::fmt::{self, Write};
+
+use either::Either;
+use proc_macro2::Literal;
 
 use crate::{
-	help::Diagnostic,
+	attributes::OuterAttribute,
+	expressions::{path_expressions::PathExpression, tuple_expressions::TupleIndex},
+	help::{catalog::Message, codes::Code, Applicability, Diagnostic, Edit, Expected, Suggestion},
 	identifiers::Identifier,
-	io::{random_access::TokenTree, Input, Parse},
+	io::{
+		arena::Node,
+		default_span,
+		first::First,
+		random_access::{self, TokenTree},
+		Input, Parse,
+	},
+	macros::MacroInvocation,
+	names::paths::PathInExpression,
 	tokens::{
+		delimiters::{Braces, Brackets, Parentheses},
 		keywords::{If, In, Mut, Ref},
 		punctuation::{
-			colon_colon, At, Comma, DotDotDot, DotDotEq, Eq, FatArrow, Minus, Or, Underscore,
+			And, AndAnd, At, Colon, Comma, DotDot, DotDotDot, DotDotEq, Eq, FatArrow, Minus, Or,
+			PathSep, Underscore,
 		},
 	},
 };
 
+/// Patterns separated by `|`, with an optional leading `|`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct Pattern<'a> {
-	or: Option<Or>,
-	pattern_no_top_alt: PatternNoTopAlt<'a>,
-	rest: Vec<(Or, PatternNoTopAlt<'a>)>,
+	pub or: Option<Or>,
+	pub pattern_no_top_alt: PatternNoTopAlt<'a>,
+	pub rest: Vec<(Or, PatternNoTopAlt<'a>)>,
 }
 
 impl<'a> Parse<'a> for Pattern<'a> {
@@ -38,17 +56,56 @@ impl<'a> Parse<'a> for Pattern<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
-		<(Option<Or>, PatternNoTopAlt, Vec<(Or, PatternNoTopAlt)>)>::describe(w)?;
-		w.write_str(" before (end|`=>`|`,`|`=`|`|`|`if`|`in`)")
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-before")
+			.arg(
+				"item",
+				Expected::of::<(Option<Or>, PatternNoTopAlt, Vec<(Or, PatternNoTopAlt)>)>(),
+			)
+			.arg("terminators", "(end|`=>`|`,`|`=`|`|`|`if`|`in`)")
+			.write(w)
 	}
 }
 
+/// A pattern without top-level `|`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs, clippy::large_enum_variant)]
 pub enum PatternNoTopAlt<'a> {
 	PatternWithoutRange(PatternWithoutRange<'a>),
 	RangePattern(RangePattern<'a>),
 }
 
+impl<'a> Parse<'a> for PatternNoTopAlt<'a> {
+	#[allow(clippy::same_functions_in_if_condition)]
+	fn parse(input: &mut Input<'a>) -> Self {
+		// Range patterns come first, since their lower bound would also parse as pattern on its own.
+		if let (range_pattern, Ok(())) = input.try_parse() {
+			Self::RangePattern(range_pattern)
+		} else if let (pattern_without_range, Ok(())) = input.try_parse() {
+			Self::PatternWithoutRange(pattern_without_range)
+		} else {
+			input.error_expected()
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		w.write_char('(')?;
+		PatternWithoutRange::describe(w)?;
+		w.write_char('|')?;
+		RangePattern::describe(w)?;
+		w.write_char(')')
+	}
+}
+
+impl Default for PatternNoTopAlt<'_> {
+	fn default() -> Self {
+		Self::PatternWithoutRange(PatternWithoutRange::default())
+	}
+}
+
+/// A pattern that isn't a range pattern.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub enum PatternWithoutRange<'a> {
 	LiteralPattern(LiteralPattern),
 	IdentifierPattern(IdentifierPattern<'a>),
@@ -64,8 +121,71 @@ pub enum PatternWithoutRange<'a> {
 	MacroInvocation(MacroInvocation<'a>),
 }
 
+impl<'a> Parse<'a> for PatternWithoutRange<'a> {
+	#[allow(clippy::same_functions_in_if_condition)]
+	fn parse(input: &mut Input<'a>) -> Self {
+		// Each alternative is only attempted if its FIRST set admits the next token.
+		// Patterns that continue after a leading path are tried before `IdentifierPattern`, which would match only that path.
+		if let Some(literal_pattern) = input.parse() {
+			Self::LiteralPattern(literal_pattern)
+		} else if let Some(wildcard_pattern) = input.parse() {
+			Self::WildcardPattern(wildcard_pattern)
+		} else if let Some(rest_pattern) = input.parse() {
+			Self::RestPattern(rest_pattern)
+		} else if let Some(reference_pattern) = input.parse() {
+			Self::ReferencePattern(reference_pattern)
+		} else if let Some(struct_pattern) = input.parse() {
+			Self::StructPattern(struct_pattern)
+		} else if let Some(tuple_struct_pattern) = input.parse() {
+			Self::TupleStructPattern(tuple_struct_pattern)
+		} else if let Some(macro_invocation) = input.parse() {
+			Self::MacroInvocation(macro_invocation)
+		} else if let Some(tuple_pattern) = input.parse() {
+			Self::TuplePattern(tuple_pattern)
+		} else if let Some(grouped_pattern) = input.parse() {
+			Self::GroupedPattern(grouped_pattern)
+		} else if let Some(slice_pattern) = input.parse() {
+			Self::SlicePattern(slice_pattern)
+		} else if let Some(identifier_pattern) = input.parse() {
+			Self::IdentifierPattern(identifier_pattern)
+		} else if let Some(path_pattern) = input.parse() {
+			Self::PathPattern(path_pattern)
+		} else {
+			input.error_expected()
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-pattern-without-range").write(w)
+	}
+
+	fn first() -> First {
+		LiteralPattern::first()
+			.or(WildcardPattern::first())
+			.or(RestPattern::first())
+			.or(ReferencePattern::first())
+			.or(StructPattern::first())
+			.or(TupleStructPattern::first())
+			.or(MacroInvocation::first())
+			.or(TuplePattern::first())
+			.or(GroupedPattern::first())
+			.or(SlicePattern::first())
+			.or(IdentifierPattern::first())
+			.or(PathPattern::first())
+	}
+}
+
+impl Default for PatternWithoutRange<'_> {
+	fn default() -> Self {
+		Self::WildcardPattern(WildcardPattern::default())
+	}
+}
+
+/// A literal, like `1`, `'c'` or `"text"`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub struct LiteralPattern {
-	literal: Literal,
+	pub literal: Literal,
 }
 
 impl Parse<'_> for LiteralPattern {
@@ -74,46 +194,97 @@ impl Parse<'_> for LiteralPattern {
 			literal: input.parse(),
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Literal::describe(w)
+	}
+
+	fn first() -> First {
+		Literal::first()
+	}
+}
+
+impl Default for LiteralPattern {
+	fn default() -> Self {
+		Self {
+			literal: default_literal(),
+		}
+	}
+}
+
+/// Stands in for a missing [`Literal`], which has no [`Default`] of its own.
+pub(crate) fn default_literal() -> Literal {
+	let mut literal = Literal::string("EXPECTED LITERAL");
+	literal.set_span(default_span());
+	literal
 }
 
 impl Parse<'_> for Literal {
 	fn parse(input: &mut Input<'_>) -> Self {
-		if let Some((first, rest)) = input.cursor.split_first() {
-			match first {
-				TokenTree::Literal(literal) => {
-					input.cursor = rest;
-					return literal.clone();
-				}
-				_ => (),
-			}
+		if let Some(TokenTree::Literal(literal)) = input.cursor.first() {
+			input.advance(1);
+			return literal.clone();
 		}
 
-		input.diagnostics.push(Diagnostic { todo: PhantomData });
+		input
+			.diagnostics
+			.push(Diagnostic::expected::<Literal>(input));
+		default_literal()
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-literal").write(w)
+	}
 
-		let mut literal = Literal::string("EXPECTED LITERAL");
-		literal.set_span(Span::mixed_site());
-		literal
+	fn first() -> First {
+		First::literal()
 	}
 }
 
+/// `ref mut name @ subpattern`, of which only the name is required.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct IdentifierPattern<'a> {
 	pub r#ref: Option<Ref>,
 	pub r#mut: Option<Mut>,
 	pub identifier: Identifier,
-	pub at: Option<Box<(At, PatternNoTopAlt<'a>)>>,
+	pub at: Option<Node<'a, (At, PatternNoTopAlt<'a>)>>,
 }
 
 impl<'a> Parse<'a> for IdentifierPattern<'a> {
 	fn parse(input: &mut Input<'a>) -> Self {
+		let r#ref = input.parse();
+		let r#mut = input.parse();
+		let identifier = input.parse();
+		if input.peek::<PathSep>() {
+			// That's a `PathPattern`.
+			return input.error_expected();
+		}
 		Self {
-			r#ref: input.parse(),
-			r#mut: input.parse(),
-			identifier: input.parse(),
+			r#ref,
+			r#mut,
+			identifier,
 			at: input.parse(),
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(
+			Option<Ref>,
+			Option<Mut>,
+			Identifier,
+			Option<(At, PatternNoTopAlt)>,
+		)>::describe(w)
+	}
+
+	fn first() -> First {
+		Ref::first().or(Mut::first()).or(Identifier::first())
+	}
 }
 
+/// `_`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct WildcardPattern {
 	pub underscore: Underscore,
 }
@@ -124,10 +295,21 @@ impl Parse<'_> for WildcardPattern {
 			underscore: input.parse(),
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Underscore::describe(w)
+	}
+
+	fn first() -> First {
+		Underscore::first()
+	}
 }
 
+/// `..` in a tuple, tuple struct or slice pattern.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct RestPattern {
-	dot_dot: colon_colon,
+	pub dot_dot: DotDot,
 }
 
 impl Parse<'_> for RestPattern {
@@ -136,6 +318,378 @@ impl Parse<'_> for RestPattern {
 			dot_dot: input.parse(),
 		}
 	}
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		DotDot::describe(w)
+	}
+
+	fn first() -> First {
+		DotDot::first()
+	}
+}
+
+/// `&pattern` or `&&pattern`, optionally `mut`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub struct ReferencePattern<'a> {
+	pub op: Either<And, AndAnd>,
+	pub r#mut: Option<Mut>,
+	pub pattern_without_range: Node<'a, PatternWithoutRange<'a>>,
+}
+
+impl<'a> Parse<'a> for ReferencePattern<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			op: input.parse(),
+			r#mut: input.parse(),
+			pattern_without_range: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Either<And, AndAnd>, Option<Mut>, PatternWithoutRange)>::describe(w)
+	}
+
+	fn first() -> First {
+		And::first().or(AndAnd::first())
+	}
+}
+
+impl Default for ReferencePattern<'_> {
+	fn default() -> Self {
+		Self {
+			op: Either::Left(And::default()),
+			r#mut: None,
+			pattern_without_range: Node::default(),
+		}
+	}
+}
+
+/// `Path { fields }`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct StructPattern<'a> {
+	pub path_in_expression: PathInExpression<'a>,
+	pub braces: Braces<'a, Option<StructPatternElements<'a>>>,
+}
+
+impl<'a> Parse<'a> for StructPattern<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			path_in_expression: input.parse(),
+			braces: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(PathInExpression, Braces<Option<StructPatternElements>>)>::describe(w)
+	}
+
+	fn first() -> First {
+		PathInExpression::first()
+	}
+}
+
+/// The non-empty contents of a [`StructPattern`]'s braces.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub enum StructPatternElements<'a> {
+	StructPatternFields {
+		struct_pattern_field: StructPatternField<'a>,
+		rest: Vec<(Comma, StructPatternField<'a>)>,
+		/// The trailing comma, possibly followed by `..`.
+		et_cetera: Option<(Comma, Option<StructPatternEtCetera<'a>>)>,
+	},
+	StructPatternEtCetera(StructPatternEtCetera<'a>),
+}
+
+impl<'a> Parse<'a> for StructPatternElements<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		if let (struct_pattern_et_cetera, Ok(())) = input.try_parse() {
+			Self::StructPatternEtCetera(struct_pattern_et_cetera)
+		} else {
+			Self::StructPatternFields {
+				struct_pattern_field: input.parse(),
+				rest: input.parse(),
+				et_cetera: input.parse(),
+			}
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		w.write_char('(')?;
+		<(
+			StructPatternField,
+			Vec<(Comma, StructPatternField)>,
+			Option<(Comma, Option<StructPatternEtCetera>)>,
+		)>::describe(w)?;
+		w.write_char('|')?;
+		StructPatternEtCetera::describe(w)?;
+		w.write_char(')')
+	}
+}
+
+impl Default for StructPatternElements<'_> {
+	fn default() -> Self {
+		Self::StructPatternEtCetera(StructPatternEtCetera::default())
+	}
+}
+
+/// A field pattern, with its outer attributes.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct StructPatternField<'a> {
+	pub outer_attributes: Vec<OuterAttribute<'a>>,
+	pub variant: StructPatternFieldVariant<'a>,
+}
+
+impl<'a> Parse<'a> for StructPatternField<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			outer_attributes: input.parse(),
+			variant: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Vec<OuterAttribute>, StructPatternFieldVariant)>::describe(w)
+	}
+}
+
+/// `0: pattern`, `name: pattern` or the shorthand `ref mut name`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub enum StructPatternFieldVariant<'a> {
+	TupleIndex {
+		tuple_index: TupleIndex,
+		colon: Colon,
+		pattern: Node<'a, Pattern<'a>>,
+	},
+	Identifier {
+		identifier: Identifier,
+		colon: Colon,
+		pattern: Node<'a, Pattern<'a>>,
+	},
+	Shorthand {
+		r#ref: Option<Ref>,
+		r#mut: Option<Mut>,
+		identifier: Identifier,
+	},
+}
+
+impl<'a> Parse<'a> for StructPatternFieldVariant<'a> {
+	#[allow(clippy::same_functions_in_if_condition)]
+	fn parse(input: &mut Input<'a>) -> Self {
+		if let ((tuple_index, colon, pattern), Ok(())) = input.try_parse() {
+			Self::TupleIndex {
+				tuple_index,
+				colon,
+				pattern,
+			}
+		} else if let ((identifier, colon, pattern), Ok(())) = input.try_parse() {
+			Self::Identifier {
+				identifier,
+				colon,
+				pattern,
+			}
+		} else {
+			Self::Shorthand {
+				r#ref: input.parse(),
+				r#mut: input.parse(),
+				identifier: input.parse(),
+			}
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		w.write_char('(')?;
+		<(TupleIndex, Colon, Pattern)>::describe(w)?;
+		w.write_char('|')?;
+		<(Identifier, Colon, Pattern)>::describe(w)?;
+		w.write_char('|')?;
+		<(Option<Ref>, Option<Mut>, Identifier)>::describe(w)?;
+		w.write_char(')')
+	}
+}
+
+impl Default for StructPatternFieldVariant<'_> {
+	fn default() -> Self {
+		Self::Shorthand {
+			r#ref: None,
+			r#mut: None,
+			identifier: Identifier::default(),
+		}
+	}
+}
+
+/// `..` at the end of a [`StructPattern`], with its outer attributes.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct StructPatternEtCetera<'a> {
+	pub outer_attributes: Vec<OuterAttribute<'a>>,
+	pub dot_dot: DotDot,
+}
+
+impl<'a> Parse<'a> for StructPatternEtCetera<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			outer_attributes: input.parse(),
+			dot_dot: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Vec<OuterAttribute>, DotDot)>::describe(w)
+	}
+}
+
+/// `Path(patterns)`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct TupleStructPattern<'a> {
+	pub path_in_expression: PathInExpression<'a>,
+	pub parentheses: Parentheses<'a, Option<PatternList<'a>>>,
+}
+
+impl<'a> Parse<'a> for TupleStructPattern<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			path_in_expression: input.parse(),
+			parentheses: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(PathInExpression, Parentheses<Option<PatternList>>)>::describe(w)
+	}
+
+	fn first() -> First {
+		PathInExpression::first()
+	}
+}
+
+/// Comma-separated [`Pattern`]s, with an optional trailing comma.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct PatternList<'a> {
+	pub pattern: Node<'a, Pattern<'a>>,
+	pub rest: Vec<(Comma, Node<'a, Pattern<'a>>)>,
+	pub comma: Option<Comma>,
+}
+
+impl<'a> Parse<'a> for PatternList<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			pattern: input.parse(),
+			rest: input.parse(),
+			comma: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Pattern, Vec<(Comma, Pattern)>, Option<Comma>)>::describe(w)
+	}
+}
+
+/// `()`, `(..)` or parentheses around at least one top-level comma.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct TuplePattern<'a> {
+	pub parentheses: Parentheses<'a, Option<PatternList<'a>>>,
+}
+
+impl<'a> Parse<'a> for TuplePattern<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		let is_tuple = random_access::group_contents(input.cursor).is_some_and(|contents| {
+			let mut contents = input.with_cursor(contents);
+			contents.is_end()
+				|| random_access::group_contains_punct(input.cursor, ',')
+				|| (contents.try_parse::<RestPattern>().1.is_ok() && contents.is_end())
+		});
+		if !is_tuple {
+			// That's a `GroupedPattern`.
+			return input.error_expected();
+		}
+		Self {
+			parentheses: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Parentheses::<Option<PatternList>>::describe(w)
+	}
+
+	fn first() -> First {
+		Parentheses::<Option<PatternList>>::first()
+	}
+}
+
+/// `( pattern )`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct GroupedPattern<'a> {
+	pub parentheses: Parentheses<'a, Node<'a, Pattern<'a>>>,
+}
+
+impl<'a> Parse<'a> for GroupedPattern<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			parentheses: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Parentheses::<Pattern>::describe(w)
+	}
+
+	fn first() -> First {
+		Parentheses::<Pattern>::first()
+	}
+}
+
+/// `[patterns]`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct SlicePattern<'a> {
+	pub brackets: Brackets<'a, Option<PatternList<'a>>>,
+}
+
+impl<'a> Parse<'a> for SlicePattern<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			brackets: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Brackets::<Option<PatternList>>::describe(w)
+	}
+
+	fn first() -> First {
+		Brackets::<Option<PatternList>>::first()
+	}
+}
+
+/// A path to a constant, unit struct or unit variant.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct PathPattern<'a> {
+	pub path_expression: PathExpression<'a>,
+}
+
+impl<'a> Parse<'a> for PathPattern<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			path_expression: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		PathExpression::describe(w)
+	}
+
+	fn first() -> First {
+		PathExpression::first()
+	}
 }
 
 /// # Skips
@@ -144,8 +698,10 @@ impl Parse<'_> for RestPattern {
 /// - [`RangePatternBound`] [`DotDotEq`]
 /// - [`DotDot`] [`RangePatternBound`]
 ///
-/// Unlike with range expressions, no exclusive range patterns exists.  
+/// Unlike with range expressions, no exclusive range patterns exists.\
 /// (If you would like to accept one, try that first.)
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub enum RangePattern<'a> {
 	RangeInclusivePattern(RangeInclusivePattern<'a>),
 	RangeFromPattern(RangeFromPattern<'a>),
@@ -154,25 +710,43 @@ pub enum RangePattern<'a> {
 }
 
 impl<'a> Parse<'a> for RangePattern<'a> {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut Input<'a>) -> Self {
 		if let (rip, Ok(())) = input.try_parse() {
-			Self::RangeFromPattern(rip)
+			Self::RangeInclusivePattern(rip)
 		} else if let (rfp, Ok(())) = input.try_parse() {
 			Self::RangeFromPattern(rfp)
 		} else if let (rtip, Ok(())) = input.try_parse() {
 			Self::RangeToInclusivePattern(rtip)
 		} else if let (orp, Ok(())) = input.try_parse() {
 			Self::ObsoleteRangePattern(orp)
-		} else if let ((lower, _, upper), Ok(())) =
-			input.try_parse::<(RangePatternBound, colon_colon, RangePatternBound)>()
-		{
-			todo!()
 		} else {
-			todo!()
+			input.error_expected()
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		w.write_char('(')?;
+		RangeInclusivePattern::describe(w)?;
+		w.write_char('|')?;
+		RangeFromPattern::describe(w)?;
+		w.write_char('|')?;
+		RangeToInclusivePattern::describe(w)?;
+		w.write_char('|')?;
+		ObsoleteRangePattern::describe(w)?;
+		w.write_char(')')
+	}
 }
 
+impl Default for RangePattern<'_> {
+	fn default() -> Self {
+		Self::RangeInclusivePattern(RangeInclusivePattern::default())
+	}
+}
+
+/// `lower..=upper`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct RangeInclusivePattern<'a> {
 	pub lower_inclusive_bound: RangePatternBound<'a>,
 	pub dot_dot_eq: DotDotEq,
@@ -187,11 +761,18 @@ impl<'a> Parse<'a> for RangeInclusivePattern<'a> {
 			upper_inclusive_bound: input.parse(),
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(RangePatternBound, DotDotEq, RangePatternBound)>::describe(w)
+	}
 }
 
+/// `lower..`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct RangeFromPattern<'a> {
-	lower_inclusive_bound: RangePatternBound<'a>,
-	dot_dot: colon_colon,
+	pub lower_inclusive_bound: RangePatternBound<'a>,
+	pub dot_dot: DotDot,
 }
 
 impl<'a> Parse<'a> for RangeFromPattern<'a> {
@@ -201,11 +782,18 @@ impl<'a> Parse<'a> for RangeFromPattern<'a> {
 			dot_dot: input.parse(),
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(RangePatternBound, DotDot)>::describe(w)
+	}
 }
 
+/// `..=upper`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct RangeToInclusivePattern<'a> {
-	dot_dot_eq: DotDotEq,
-	upper_inclusive_bound: RangePatternBound<'a>,
+	pub dot_dot_eq: DotDotEq,
+	pub upper_inclusive_bound: RangePatternBound<'a>,
 }
 
 impl<'a> Parse<'a> for RangeToInclusivePattern<'a> {
@@ -215,26 +803,62 @@ impl<'a> Parse<'a> for RangeToInclusivePattern<'a> {
 			upper_inclusive_bound: input.parse(),
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(DotDotEq, RangePatternBound)>::describe(w)
+	}
 }
 
 /// Removed in edition change.
 /// Prefer [`RangeInclusivePattern`].
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct ObsoleteRangePattern<'a> {
-	lower_inclusive_bound: RangePatternBound<'a>,
-	dot_dot_dot: DotDotDot,
-	upper_inclusive_bound: RangePatternBound<'a>,
+	pub lower_inclusive_bound: RangePatternBound<'a>,
+	pub dot_dot_dot: DotDotDot,
+	pub upper_inclusive_bound: RangePatternBound<'a>,
 }
 
 impl<'a> Parse<'a> for ObsoleteRangePattern<'a> {
 	fn parse(input: &mut Input<'a>) -> Self {
+		let lower_inclusive_bound = input.parse();
+		let start = input.cursor;
+		let dot_dot_dot = input.parse();
+		let dots = &start[..start.len() - input.cursor.len()];
+		if let Some(span) =
+			random_access::span(dots).filter(|_| !input.edition.allows_obsolete_range_patterns())
+		{
+			input.diagnostics.push(
+				Diagnostic::error(
+					span,
+					Message::new("obsolete-range-pattern").arg("edition", input.edition),
+				)
+				.with_code(Code::EDITION_GATED)
+				.with_suggestion(Suggestion {
+					message: Message::new("use-inclusive-range"),
+					edit: Edit::Replace(span),
+					replacement: "..=".to_string(),
+					applicability: Applicability::MachineApplicable,
+				})
+				.located(input, dots)
+				.recovered(),
+			);
+		}
 		Self {
-			lower_inclusive_bound: input.parse(),
-			dot_dot_dot: input.parse(),
+			lower_inclusive_bound,
+			dot_dot_dot,
 			upper_inclusive_bound: input.parse(),
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(RangePatternBound, DotDotDot, RangePatternBound)>::describe(w)
+	}
 }
 
+/// A possibly negated literal or a path as bound of a [`RangePattern`].
+#[derive(Debug, Clone)]
+#[allow(missing_docs, clippy::large_enum_variant)]
 pub enum RangePatternBound<'a> {
 	Literal(Literal),
 	MinusLiteral { minus: Minus, literal: Literal },
@@ -242,6 +866,7 @@ pub enum RangePatternBound<'a> {
 }
 
 impl<'a> Parse<'a> for RangePatternBound<'a> {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut Input<'a>) -> Self {
 		if let (literal, Ok(())) = input.try_parse() {
 			Self::Literal(literal)
@@ -250,7 +875,47 @@ impl<'a> Parse<'a> for RangePatternBound<'a> {
 		} else if let (path_expression, Ok(())) = input.try_parse() {
 			Self::PathExpression(path_expression)
 		} else {
-			todo!()
+			input.error_expected()
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		w.write_char('(')?;
+		Literal::describe(w)?;
+		w.write_char('|')?;
+		<(Minus, Literal)>::describe(w)?;
+		w.write_char('|')?;
+		PathExpression::describe(w)?;
+		w.write_char(')')
+	}
+}
+
+impl Default for RangePatternBound<'_> {
+	fn default() -> Self {
+		Self::PathExpression(PathExpression::default())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{help::codes::Code, io::edition::Edition, parse2_in};
+
+	use super::Pattern;
+
+	fn codes(source: &str, edition: Edition) -> Vec<Option<Code>> {
+		parse2_in(source.parse().unwrap(), edition, |parsing| {
+			let (_, diagnostics): (Pattern, _) = parsing.parse();
+			diagnostics.codes()
+		})
+	}
+
+	#[test]
+	fn obsolete_range_patterns_are_edition_gated() {
+		assert_eq!(codes("0...9", Edition::Edition2018), []);
+		assert_eq!(
+			codes("0...9", Edition::Edition2021),
+			[Some(Code::EDITION_GATED)]
+		);
+		assert_eq!(codes("0..=9", Edition::Edition2021), []);
+	}
 }
@@ -1,47 +1,88 @@
+//! [Statements](https://doc.rust-lang.org/stable/reference/statements.html).
+
+use std::fmt::{self, Write};
+
 use crate::{
-	expressions::block_expressions::BlockExpression,
-	io::Parse,
+	attributes::OuterAttribute,
+	expressions::{
+		block_expressions::BlockExpression, Expression, ExpressionWithBlock, ExpressionWithoutBlock,
+	},
+	help::catalog::Message,
+	io::{arena::Node, first::First, Parse},
 	items::Item,
+	macros::MacroInvocationSemi,
 	patterns::PatternNoTopAlt,
 	tokens::{
 		keywords::{Else, Let},
 		punctuation::{Colon, Eq, Semi},
 	},
+	type_system::types::Type,
 };
 
+/// `;`, an item, a `let` statement, an expression statement or a macro invocation.
+#[derive(Debug, Clone)]
+#[allow(missing_docs, clippy::large_enum_variant)]
 pub enum Statement<'a> {
 	Semi(Semi),
-	Item(Box<Item<'a>>),
+	Item(Node<'a, Item<'a>>),
 	LetStatement(LetStatement<'a>),
 	ExpressionStatement(ExpressionStatement<'a>),
 	MacroInvocationSemi(MacroInvocationSemi<'a>),
 }
 
 impl<'a> Parse<'a> for Statement<'a> {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut crate::io::Input<'a>) -> Self {
-		if let (semi, Ok(())) = input.parse() {
+		// Each alternative is only attempted if its FIRST set admits the next token.
+		if let Some(semi) = input.parse() {
 			Self::Semi(semi)
-		} else if let (item, Ok(())) = input.parse() {
+		} else if let Some(item) = input.parse() {
 			Self::Item(item)
-		} else if let (let_statement, Ok(())) = input.parse() {
+		} else if input.peek::<Let>() {
+			// Nothing else starts with `let`, so its errors are reported as they are.
+			Self::LetStatement(input.parse())
+		} else if let Some(let_statement) = input.parse() {
 			Self::LetStatement(let_statement)
-		} else if let (expression_statement, Ok(())) = input.parse() {
+		} else if let Some(expression_statement) = input.parse() {
 			Self::ExpressionStatement(expression_statement)
-		} else if let (macro_invocation_semi, Ok(())) = input.parse() {
+		} else if let Some(macro_invocation_semi) = input.parse() {
 			Self::MacroInvocationSemi(macro_invocation_semi)
 		} else {
-			todo!()
+			input.error_expected()
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-statement").write(w)
+	}
+
+	fn first() -> First {
+		Semi::first()
+			.or(Item::first())
+			.or(LetStatement::first())
+			.or(ExpressionStatement::first())
+			.or(MacroInvocationSemi::first())
+	}
+}
+
+/// Defaults to an empty statement.
+impl Default for Statement<'_> {
+	fn default() -> Self {
+		Self::Semi(Semi::default())
+	}
 }
 
+/// `let pattern: Type = value else { … };`, where the type and assignment are optional.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct LetStatement<'a> {
-	outer_attributes: Vec<OuterAttribute<'a>>,
-	r#let: Let,
-	pattern_no_top_alt: PatternNoTopAlt<'a>,
-	r#type: Option<(Colon, Type<'a>)>,
-	assignment: Option<(Eq, Expression<'a>, Option<(Else, BlockExpression<'a>)>)>,
-	semi: Semi,
+	pub outer_attributes: Vec<OuterAttribute<'a>>,
+	pub r#let: Let,
+	pub pattern_no_top_alt: PatternNoTopAlt<'a>,
+	pub r#type: Option<(Colon, Type<'a>)>,
+	#[allow(clippy::type_complexity)]
+	pub assignment: Option<(Eq, Expression<'a>, Option<(Else, BlockExpression<'a>)>)>,
+	pub semi: Semi,
 }
 
 impl<'a> Parse<'a> for LetStatement<'a> {
@@ -55,8 +96,26 @@ impl<'a> Parse<'a> for LetStatement<'a> {
 			semi: input.parse(),
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(
+			Vec<OuterAttribute>,
+			Let,
+			PatternNoTopAlt,
+			Option<(Colon, Type)>,
+			Option<(Eq, Expression, Option<(Else, BlockExpression)>)>,
+			Semi,
+		)>::describe(w)
+	}
+
+	fn first() -> First {
+		OuterAttribute::first().or(Let::first())
+	}
 }
 
+/// An expression followed by `;`, which is optional after an expression with a block.
+#[derive(Debug, Clone)]
+#[allow(missing_docs, clippy::large_enum_variant)]
 pub enum ExpressionStatement<'a> {
 	ExpressionWithoutBlock {
 		expression_without_block: ExpressionWithoutBlock<'a>,
@@ -69,6 +128,7 @@ pub enum ExpressionStatement<'a> {
 }
 
 impl<'a> Parse<'a> for ExpressionStatement<'a> {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut crate::io::Input<'a>) -> Self {
 		if let ((expression_without_block, semi), Ok(())) = input.try_parse() {
 			Self::ExpressionWithoutBlock {
@@ -81,7 +141,123 @@ impl<'a> Parse<'a> for ExpressionStatement<'a> {
 				semi,
 			}
 		} else {
-			todo!()
+			input.error_expected()
 		}
 	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		w.write_char('(')?;
+		<(ExpressionWithoutBlock, Semi)>::describe(w)?;
+		w.write_char('|')?;
+		<(ExpressionWithBlock, Option<Semi>)>::describe(w)?;
+		w.write_char(')')
+	}
+}
+
+impl Default for ExpressionStatement<'_> {
+	fn default() -> Self {
+		Self::ExpressionWithoutBlock {
+			expression_without_block: ExpressionWithoutBlock::default(),
+			semi: Semi::default(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::{
+		expressions::block_expressions::Statements,
+		help::{codes::Code, Applicability, Diagnostic, DiagnosticsList},
+		parse_str, Parsing,
+	};
+
+	use super::Statement;
+
+	/// The messages of the errors in `source`, parsed as the contents of a block.
+	fn errors(source: &str) -> Vec<String> {
+		errors_by(source, |parsing| parsing.parse::<Statements>().1)
+	}
+
+	fn errors_by(
+		source: &str,
+		parse: impl for<'a> FnOnce(Parsing<'a>) -> DiagnosticsList<'a>,
+	) -> Vec<String> {
+		parse_str(source, |parsing| {
+			parse(parsing)
+				.into_iter()
+				.filter(Diagnostic::is_error)
+				.map(|diagnostic| diagnostic.message.to_string())
+				.collect()
+		})
+		.unwrap()
+	}
+
+	#[test]
+	fn errors_come_from_the_committed_alternative() {
+		assert_eq!(
+			errors("let x = 1 let y = 2;"),
+			["expected `;`, found `let`"]
+		);
+		assert_eq!(errors("let x = ;"), ["expected expression, found `;`"]);
+		assert_eq!(errors("let x: = 1;"), ["expected type, found `=`"]);
+		assert_eq!(
+			errors("x = if a { 1 } else ;"),
+			["expected (`{ … }`|`if` …), found `;`"]
+		);
+	}
+
+	#[test]
+	fn unrelated_expectations_are_left_out() {
+		let errors = errors("foo() bar();");
+		assert_eq!(errors.len(), 1);
+		assert!(!errors[0].contains("`+=`"), "{errors:?}");
+	}
+
+	#[test]
+	fn independent_errors_are_all_reported() {
+		assert_eq!(
+			errors("let x = 1; let y = 2 let z = 3; let w = 4 let q = 5;"),
+			["expected `;`, found `let`", "expected `;`, found `let`"]
+		);
+		assert_eq!(
+			errors("let x: = 1; let y = ;"),
+			["expected type, found `=`", "expected expression, found `;`"]
+		);
+	}
+
+	#[test]
+	fn keywords_in_patterns_are_escaped() {
+		let diagnostics = parse_str("let type = 1;", |parsing| {
+			let (_, diagnostics): (Statement, _) = parsing.parse();
+			diagnostics
+				.into_iter()
+				.map(|diagnostic| {
+					let suggestions: Vec<_> = diagnostic
+						.suggestions
+						.into_iter()
+						.map(|suggestion| (suggestion.replacement, suggestion.applicability))
+						.collect();
+					(diagnostic.code, suggestions)
+				})
+				.collect::<Vec<_>>()
+		})
+		.unwrap();
+		assert_eq!(
+			diagnostics,
+			[(
+				Some(Code::KEYWORD_AS_IDENTIFIER),
+				vec![("r#type".to_owned(), Applicability::MachineApplicable)]
+			)]
+		);
+	}
+
+	#[test]
+	fn speculative_elements_report_their_own_errors() {
+		assert_eq!(
+			errors_by("let x = 1; let y = ;", |parsing| parsing
+				.parse::<Vec<Statement>>()
+				.1),
+			["expected expression, found `;`"]
+		);
+	}
 }
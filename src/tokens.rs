@@ -1,18 +1,31 @@
-use std::{fmt::Write, marker::PhantomData};
+//! Tokens, named after those in The Rust Reference.
 
-use proc_macro2::{extra::DelimSpan, Delimiter, Ident, Punct, Spacing, Span};
+use std::{
+	cell::OnceCell,
+	fmt::{self, Debug, Write},
+	marker::PhantomData,
+};
+
+use proc_macro2::{extra::DelimSpan, Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream};
 
 use crate::{
+	help::{catalog::Message, DiagnosticsList},
 	identifiers::Identifier,
-	io::{random_access::TokenTree, Input, Parse},
+	io::{default_span, edition::Edition, first::First, random_access::TokenTree, Input, Parse},
 };
 
+#[allow(missing_docs)]
 pub mod keywords;
+#[allow(missing_docs)]
 pub mod punctuation;
 
+/// `'label` or `'lifetime`, except `'static` and `'_`.
+#[derive(Debug, Clone, Default)]
 pub struct LifetimeOrLabel {
-	apostrophe: SPunct<'\'', true>,
-	identifier: Identifier,
+	/// The leading `'`.
+	pub apostrophe: SPunct<'\'', true>,
+	/// The name after it.
+	pub identifier: Identifier,
 }
 
 impl Parse<'_> for LifetimeOrLabel {
@@ -23,20 +36,17 @@ impl Parse<'_> for LifetimeOrLabel {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
-		w.write_str("LIFETIME_OR_LABEL")
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-lifetime-or-label").write(w)
 	}
-}
 
-impl Default for LifetimeOrLabel {
-	fn default() -> Self {
-		Self {
-			apostrophe: SPunct::default(),
-			identifier: Identifier::default(),
-		}
+	fn first() -> First {
+		First::punct('\'')
 	}
 }
 
+/// A single [`Punct`] `CH`, joint with the next one iff `JOINT`.
+#[derive(Debug, Clone)]
 pub struct SPunct<const CH: char, const JOINT: bool> {
 	punct: Punct,
 }
@@ -54,11 +64,21 @@ impl<const CH: char, const JOINT: bool> SPunct<CH, JOINT> {
 		punct.set_span(span);
 		Self { punct }
 	}
+
+	/// Takes the [`Punct`] at `input`'s cursor, which [`puncts_match`] already checked, whatever its spacing.
+	fn take(input: &mut Input<'_>) -> Self {
+		let Some(TokenTree::Punct(punct)) = input.cursor.first() else {
+			unreachable!("checked by `puncts_match`")
+		};
+		let punct = punct.clone();
+		input.consume(1);
+		Self { punct }
+	}
 }
 
 impl<const CH: char, const JOINT: bool> Default for SPunct<CH, JOINT> {
 	fn default() -> Self {
-		Self::new(Span::mixed_site())
+		Self::new(default_span())
 	}
 }
 
@@ -87,28 +107,80 @@ impl<const CH: char, const JOINT: bool> TryFrom<Punct> for SPunct<CH, JOINT> {
 impl<const CH: char, const JOINT: bool> Parse<'_> for SPunct<CH, JOINT> {
 	fn parse(input: &mut Input<'_>) -> Self {
 		match input.cursor.first() {
-			None => todo!(),
 			Some(TokenTree::Punct(punct)) => match punct.clone().try_into() {
 				Ok(s_punct) => {
 					input.consume(1);
 					s_punct
 				}
-				Err(punct) => todo!(),
+				Err(_) => input.error_expected(),
 			},
-			Some(_) => todo!(),
+			_ => input.error_expected(),
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
-		unimplemented!("To parse custom punctuation, please wrap all `Punct`s in `Punctuation`.")
+	/// To parse custom punctuation, prefer wrapping all [`SPunct`]s in [`Punctuation`], which describes them together.
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		w.write_char('`')?;
+		w.write_char(CH)?;
+		w.write_char('`')
+	}
+
+	fn first() -> First {
+		First::punct(CH)
 	}
 }
 
+/// Rust's multi-character punctuation tokens, which the lexer glues greedily from joint [`Punct`]s.
+const GLUED: &[&str] = &[
+	"<<=", ">>=", "...", "..=", "::", "->", "=>", "==", "!=", "<=", ">=", "&&", "||", "+=", "-=",
+	"*=", "/=", "%=", "^=", "&=", "|=", "<<", ">>", "..",
+];
+
+/// Whether the [`Punct`]s at the start of `tokens` spell `chars`, all but the last one [joint](`Spacing::Joint`).
+pub(crate) fn spells(tokens: &[TokenTree], chars: impl IntoIterator<Item = char>) -> bool {
+	let mut chars = chars.into_iter().peekable();
+	let mut tokens = tokens.iter();
+	while let Some(ch) = chars.next() {
+		match tokens.next() {
+			Some(TokenTree::Punct(punct))
+				if punct.as_char() == ch
+					&& (chars.peek().is_none() || punct.spacing() == Spacing::Joint) => {}
+			_ => return false,
+		}
+	}
+	true
+}
+
+/// How many [`Punct`]s the lexer would glue into the token at the start of `tokens`, or `0` if it isn't punctuation.
+pub(crate) fn glued_len(tokens: &[TokenTree]) -> usize {
+	match tokens.first() {
+		Some(TokenTree::Punct(_)) => GLUED
+			.iter()
+			.filter(|glued| spells(tokens, glued.chars()))
+			.map(|glued| glued.len())
+			.max()
+			.unwrap_or(1),
+		_ => 0,
+	}
+}
+
+/// Whether the [`Punct`]s at `input`'s cursor spell `chars` as one token.
+///
+/// The last one may be joint too, as long as the lexer wouldn't glue it to what follows, like the `::` in `::<`.
+fn puncts_match(input: &Input<'_>, chars: &[char]) -> bool {
+	spells(input.cursor, chars.iter().copied()) && glued_len(input.cursor) <= chars.len()
+}
+
+/// A multi-character punctuation token, as tuple of [`SPunct`]s of which all but the last are joint.
+#[derive(Debug, Clone)]
 pub struct Punctuation<SPunctsTuple> {
+	#[allow(missing_docs)]
 	pub s_puncts: SPunctsTuple,
 }
 
 impl<const CH1: char> Punctuation<(SPunct<CH1, false>,)> {
+	/// Creates this punctuation with each character at `span`.
+	#[must_use]
 	pub fn new(span: Span) -> Self {
 		Self {
 			s_puncts: (SPunct::new(span),),
@@ -117,6 +189,8 @@ impl<const CH1: char> Punctuation<(SPunct<CH1, false>,)> {
 }
 
 impl<const CH1: char, const CH2: char> Punctuation<(SPunct<CH1, true>, SPunct<CH2, false>)> {
+	/// Creates this punctuation with each character at `span`.
+	#[must_use]
 	pub fn new(span: Span) -> Self {
 		Self {
 			s_puncts: (SPunct::new(span), SPunct::new(span)),
@@ -126,6 +200,8 @@ impl<const CH1: char, const CH2: char> Punctuation<(SPunct<CH1, true>, SPunct<CH
 impl<const CH1: char, const CH2: char, const CH3: char>
 	Punctuation<(SPunct<CH1, true>, SPunct<CH2, true>, SPunct<CH3, false>)>
 {
+	/// Creates this punctuation with each character at `span`.
+	#[must_use]
 	pub fn new(span: Span) -> Self {
 		Self {
 			s_puncts: (SPunct::new(span), SPunct::new(span), SPunct::new(span)),
@@ -141,6 +217,8 @@ impl<const CH1: char, const CH2: char, const CH3: char, const CH4: char>
 		SPunct<CH4, false>,
 	)>
 {
+	/// Creates this punctuation with each character at `span`.
+	#[must_use]
 	pub fn new(span: Span) -> Self {
 		Self {
 			s_puncts: (
@@ -162,6 +240,8 @@ impl<const CH1: char, const CH2: char, const CH3: char, const CH4: char, const C
 		SPunct<CH5, false>,
 	)>
 {
+	/// Creates this punctuation with each character at `span`.
+	#[must_use]
 	pub fn new(span: Span) -> Self {
 		Self {
 			s_puncts: (
@@ -192,6 +272,8 @@ impl<
 		SPunct<CH6, false>,
 	)>
 {
+	/// Creates this punctuation with each character at `span`.
+	#[must_use]
 	pub fn new(span: Span) -> Self {
 		Self {
 			s_puncts: (
@@ -208,49 +290,86 @@ impl<
 
 impl<const CH1: char> Parse<'_> for Punctuation<(SPunct<CH1, false>,)> {
 	fn parse(input: &mut Input<'_>) -> Self {
+		if !puncts_match(input, &[CH1]) {
+			return input.error_expected();
+		}
 		Self {
-			s_puncts: (input.parse(),),
+			s_puncts: (SPunct::take(input),),
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		w.write_char('`')?;
 		w.write_char(CH1)?;
 		w.write_char('`')
 	}
+
+	fn first() -> First {
+		First::punct(CH1)
+	}
+
+	fn insertion() -> Option<String> {
+		Some([CH1].into_iter().collect())
+	}
 }
 impl<const CH1: char, const CH2: char> Parse<'_>
 	for Punctuation<(SPunct<CH1, true>, SPunct<CH2, false>)>
 {
 	fn parse(input: &mut Input<'_>) -> Self {
+		if !puncts_match(input, &[CH1, CH2]) {
+			return input.error_expected();
+		}
 		Self {
-			s_puncts: (input.parse(), input.parse()),
+			s_puncts: (SPunct::take(input), SPunct::take(input)),
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		w.write_char('`')?;
 		w.write_char(CH1)?;
 		w.write_char(CH2)?;
 		w.write_char('`')
 	}
+
+	fn first() -> First {
+		First::punct(CH1)
+	}
+
+	fn insertion() -> Option<String> {
+		Some([CH1, CH2].into_iter().collect())
+	}
 }
 impl<const CH1: char, const CH2: char, const CH3: char> Parse<'_>
 	for Punctuation<(SPunct<CH1, true>, SPunct<CH2, true>, SPunct<CH3, false>)>
 {
 	fn parse(input: &mut Input<'_>) -> Self {
+		if !puncts_match(input, &[CH1, CH2, CH3]) {
+			return input.error_expected();
+		}
 		Self {
-			s_puncts: (input.parse(), input.parse(), input.parse()),
+			s_puncts: (
+				SPunct::take(input),
+				SPunct::take(input),
+				SPunct::take(input),
+			),
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		w.write_char('`')?;
 		w.write_char(CH1)?;
 		w.write_char(CH2)?;
 		w.write_char(CH3)?;
 		w.write_char('`')
 	}
+
+	fn first() -> First {
+		First::punct(CH1)
+	}
+
+	fn insertion() -> Option<String> {
+		Some([CH1, CH2, CH3].into_iter().collect())
+	}
 }
 impl<const CH1: char, const CH2: char, const CH3: char, const CH4: char> Parse<'_>
 	for Punctuation<(
@@ -261,12 +380,20 @@ impl<const CH1: char, const CH2: char, const CH3: char, const CH4: char> Parse<'
 	)>
 {
 	fn parse(input: &mut Input<'_>) -> Self {
+		if !puncts_match(input, &[CH1, CH2, CH3, CH4]) {
+			return input.error_expected();
+		}
 		Self {
-			s_puncts: (input.parse(), input.parse(), input.parse(), input.parse()),
+			s_puncts: (
+				SPunct::take(input),
+				SPunct::take(input),
+				SPunct::take(input),
+				SPunct::take(input),
+			),
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		w.write_char('`')?;
 		w.write_char(CH1)?;
 		w.write_char(CH2)?;
@@ -274,6 +401,14 @@ impl<const CH1: char, const CH2: char, const CH3: char, const CH4: char> Parse<'
 		w.write_char(CH4)?;
 		w.write_char('`')
 	}
+
+	fn first() -> First {
+		First::punct(CH1)
+	}
+
+	fn insertion() -> Option<String> {
+		Some([CH1, CH2, CH3, CH4].into_iter().collect())
+	}
 }
 impl<const CH1: char, const CH2: char, const CH3: char, const CH4: char, const CH5: char> Parse<'_>
 	for Punctuation<(
@@ -285,18 +420,21 @@ impl<const CH1: char, const CH2: char, const CH3: char, const CH4: char, const C
 	)>
 {
 	fn parse(input: &mut Input<'_>) -> Self {
+		if !puncts_match(input, &[CH1, CH2, CH3, CH4, CH5]) {
+			return input.error_expected();
+		}
 		Self {
 			s_puncts: (
-				input.parse(),
-				input.parse(),
-				input.parse(),
-				input.parse(),
-				input.parse(),
+				SPunct::take(input),
+				SPunct::take(input),
+				SPunct::take(input),
+				SPunct::take(input),
+				SPunct::take(input),
 			),
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		w.write_char('`')?;
 		w.write_char(CH1)?;
 		w.write_char(CH2)?;
@@ -305,6 +443,14 @@ impl<const CH1: char, const CH2: char, const CH3: char, const CH4: char, const C
 		w.write_char(CH5)?;
 		w.write_char('`')
 	}
+
+	fn first() -> First {
+		First::punct(CH1)
+	}
+
+	fn insertion() -> Option<String> {
+		Some([CH1, CH2, CH3, CH4, CH5].into_iter().collect())
+	}
 }
 impl<
 		const CH1: char,
@@ -324,19 +470,22 @@ impl<
 	)>
 {
 	fn parse(input: &mut Input<'_>) -> Self {
+		if !puncts_match(input, &[CH1, CH2, CH3, CH4, CH5, CH6]) {
+			return input.error_expected();
+		}
 		Self {
 			s_puncts: (
-				input.parse(),
-				input.parse(),
-				input.parse(),
-				input.parse(),
-				input.parse(),
-				input.parse(),
+				SPunct::take(input),
+				SPunct::take(input),
+				SPunct::take(input),
+				SPunct::take(input),
+				SPunct::take(input),
+				SPunct::take(input),
 			),
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		w.write_char('`')?;
 		w.write_char(CH1)?;
 		w.write_char(CH2)?;
@@ -346,6 +495,14 @@ impl<
 		w.write_char(CH6)?;
 		w.write_char('`')
 	}
+
+	fn first() -> First {
+		First::punct(CH1)
+	}
+
+	fn insertion() -> Option<String> {
+		Some([CH1, CH2, CH3, CH4, CH5, CH6].into_iter().collect())
+	}
 }
 
 impl<const CH1: char> Default for Punctuation<(SPunct<CH1, false>,)> {
@@ -444,75 +601,323 @@ impl<
 	}
 }
 
+/// Marks a type as standing for a keyword.
 #[deprecated = "Please don't use this directly."]
-pub trait KeywordString {
+pub trait KeywordString: 'static {
+	/// The keyword's text.
 	const KW: &'static str;
+	/// The first edition in which [`KW`](`KeywordString::KW`) is a keyword.
+	const SINCE: Edition;
+	/// Whether [`KW`](`KeywordString::KW`) is also parsed as this keyword in earlier editions,
+	/// where it's a weak keyword that's only special in some places, like `dyn` in Rust 2015.
+	const WEAK_BEFORE: bool = false;
 }
 
+/// The keyword [`KW::KW`](`KeywordString::KW`).
 #[allow(deprecated)]
 pub struct Keyword<KW: KeywordString> {
 	ident: Ident,
 	phantom: PhantomData<KW>,
 }
 
-trait Delimiter_ {
+#[allow(deprecated)]
+impl<KW: KeywordString> Keyword<KW> {
+	/// Creates this keyword at `span`.
+	#[must_use]
+	pub fn new(span: Span) -> Self {
+		Self {
+			ident: Ident::new(KW::KW, span),
+			phantom: PhantomData,
+		}
+	}
+
+	/// The keyword's span.
+	#[must_use]
+	pub fn span(&self) -> Span {
+		self.ident.span()
+	}
+
+	/// Moves the keyword to `span`.
+	pub fn set_span(&mut self, span: Span) {
+		self.ident.set_span(span)
+	}
+}
+
+#[allow(deprecated)]
+impl<KW: KeywordString> Default for Keyword<KW> {
+	fn default() -> Self {
+		Self::new(default_span())
+	}
+}
+
+#[allow(deprecated)]
+impl<KW: KeywordString> Clone for Keyword<KW> {
+	fn clone(&self) -> Self {
+		Self {
+			ident: self.ident.clone(),
+			phantom: PhantomData,
+		}
+	}
+}
+
+#[allow(deprecated)]
+impl<KW: KeywordString> Debug for Keyword<KW> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("Keyword").field(&self.ident).finish()
+	}
+}
+
+#[allow(deprecated)]
+impl<KW: KeywordString> From<Keyword<KW>> for Ident {
+	fn from(val: Keyword<KW>) -> Self {
+		val.ident
+	}
+}
+
+#[allow(deprecated)]
+impl<KW: KeywordString> Parse<'_> for Keyword<KW> {
+	fn parse(input: &mut Input<'_>) -> Self {
+		match input.cursor.first() {
+			Some(TokenTree::Ident(ident))
+				if ident == KW::KW && (input.edition >= KW::SINCE || KW::WEAK_BEFORE) =>
+			{
+				input.consume(1);
+				Self {
+					ident: ident.clone(),
+					phantom: PhantomData,
+				}
+			}
+			_ => input.error_expected(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		w.write_char('`')?;
+		w.write_str(KW::KW)?;
+		w.write_char('`')
+	}
+
+	fn first() -> First {
+		First::keyword(KW::KW)
+	}
+}
+
+/// Marks a type as standing for a kind of [`Delimiter`].
+pub trait Delimiter_: 'static {
+	/// The delimiter proper.
 	const DELIMITER: Delimiter;
+	/// The opening character.
 	const OPEN: char;
+	/// The closing character.
 	const CLOSE: char;
 }
 
-enum PARENTHESIS {}
+/// `( … )`
+pub enum PARENTHESIS {}
 impl Delimiter_ for PARENTHESIS {
 	const DELIMITER: Delimiter = Delimiter::Parenthesis;
 	const OPEN: char = '(';
 	const CLOSE: char = ')';
 }
 
-enum BRACE {}
+/// `{ … }`
+pub enum BRACE {}
 impl Delimiter_ for BRACE {
 	const DELIMITER: Delimiter = Delimiter::Brace;
 	const OPEN: char = '{';
 	const CLOSE: char = '}';
 }
 
-enum BRACKET {}
+/// `[ … ]`
+pub enum BRACKET {}
 impl Delimiter_ for BRACKET {
 	const DELIMITER: Delimiter = Delimiter::Bracket;
 	const OPEN: char = '[';
 	const CLOSE: char = ']';
 }
 
+/// A delimited group, the contents of which are only parsed on first access.
 pub struct Delimited<'a, Delimiter, Contents>
 where
 	Delimiter: Delimiter_,
 {
+	#[allow(missing_docs)]
 	pub delimiter: PhantomData<Delimiter>,
+	#[allow(missing_docs)]
 	pub delim_span: DelimSpan,
-	pub enclosed: &'a [TokenTree<'a>],
-	pub contents: PhantomData<Contents>,
+	/// [`None`] iff defaulted.
+	enclosed: Option<Input<'a>>,
+	contents: OnceCell<(Contents, DiagnosticsList<'a>)>,
+}
+
+impl<'a, Delimiter, Contents> Delimited<'a, Delimiter, Contents>
+where
+	Delimiter: Delimiter_,
+	Contents: Parse<'a>,
+{
+	/// The enclosed tokens, which are empty if this group was defaulted.
+	pub fn enclosed(&self) -> &'a [TokenTree] {
+		self.enclosed
+			.as_ref()
+			.map_or(&[], |enclosed| enclosed.cursor)
+	}
+
+	/// Parses the contents on first call.
+	pub fn contents(&self) -> &Contents {
+		&self.parsed().0
+	}
+
+	/// Parses the contents on first call.
+	#[allow(clippy::missing_panics_doc)] // The contents were just parsed.
+	pub fn contents_mut(&mut self) -> &mut Contents {
+		self.parsed();
+		&mut self.contents.get_mut().expect("parsed").0
+	}
+
+	/// The diagnostics from parsing this group's contents, without those of any nested groups.
+	///
+	/// Parses the contents on first call.
+	pub fn diagnostics(&self) -> &DiagnosticsList<'a> {
+		&self.parsed().1
+	}
+
+	fn parsed(&self) -> &(Contents, DiagnosticsList<'a>) {
+		self.contents.get_or_init(|| {
+			let input = Input {
+				// The contents are parsed on demand, so never speculatively.
+				suspended: false,
+				..self
+					.enclosed
+					.clone()
+					.expect("Defaulted groups are always initialised.")
+			};
+			let checkpoint = input.diagnostics.checkpoint();
+			let contents = input.parse_exhaustively(input.cursor);
+			(contents, input.diagnostics.split_off(checkpoint))
+		})
+	}
 }
 
 impl<'a, Delimiter, Contents> Parse<'a> for Delimited<'a, Delimiter, Contents>
 where
 	Delimiter: Delimiter_,
+	Contents: Parse<'a> + Default,
 {
 	fn parse(input: &mut Input<'a>) -> Self {
 		match input.cursor.first() {
-			Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::DELIMITER => Self {
-				delimiter: PhantomData,
-				delim_span: group.delim_span(),
-				enclosed: group.contents.as_slice(),
-				contents: PhantomData,
-			},
+			Some(TokenTree::Group(group)) if group.delimiter == Delimiter::DELIMITER => {
+				let enclosed = input.group_contents().expect("at group");
+				input.consume(1);
+				Self {
+					delimiter: PhantomData,
+					delim_span: group.delim_span,
+					enclosed: Some(enclosed),
+					contents: OnceCell::new(),
+				}
+			}
 			_ => input.error_expected(),
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
-		w.write_str("`{ … }`")
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		w.write_char('`')?;
+		w.write_char(Delimiter::OPEN)?;
+		w.write_str(" … ")?;
+		w.write_char(Delimiter::CLOSE)?;
+		w.write_char('`')
+	}
+
+	fn first() -> First {
+		First::delimiter(Delimiter::DELIMITER)
+	}
+}
+
+impl<Delimiter, Contents> Clone for Delimited<'_, Delimiter, Contents>
+where
+	Delimiter: Delimiter_,
+	Contents: Clone,
+{
+	fn clone(&self) -> Self {
+		Self {
+			delimiter: PhantomData,
+			delim_span: self.delim_span,
+			enclosed: self.enclosed.clone(),
+			contents: self.contents.clone(),
+		}
+	}
+}
+
+impl<Delimiter, Contents> Debug for Delimited<'_, Delimiter, Contents>
+where
+	Delimiter: Delimiter_,
+	Contents: Debug,
+{
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Delimited")
+			.field("delimiter", &Delimiter::DELIMITER)
+			.field("delim_span", &self.delim_span)
+			.field(
+				"contents",
+				&self.contents.get().map(|(contents, _)| contents),
+			)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<Delimiter, Contents> Default for Delimited<'_, Delimiter, Contents>
+where
+	Delimiter: Delimiter_,
+	Contents: Default,
+{
+	fn default() -> Self {
+		let mut group = Group::new(Delimiter::DELIMITER, TokenStream::new());
+		group.set_span(default_span());
+		Self {
+			delimiter: PhantomData,
+			delim_span: group.delim_span(),
+			enclosed: None,
+			contents: OnceCell::from((Contents::default(), DiagnosticsList::default())),
+		}
+	}
+}
+
+/// A `T` that remembers whether it was parsed from exactly one invisible group,
+/// like those around `macro_rules!` fragment captures.
+///
+/// [`Input::parse`] looks through such groups regardless; this only keeps track of it.
+/// [`Node`](`crate::io::arena::Node`)s keep track of it by themselves.
+pub struct Captured<T> {
+	/// The invisible group's span, if there was exactly one around `inner`.
+	pub delim_span: Option<DelimSpan>,
+	#[allow(missing_docs)]
+	pub inner: T,
+}
+
+impl<'a, T: Parse<'a>> Parse<'a> for Captured<T> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		let (inner, delim_span) = input.parse_captured();
+		Self { delim_span, inner }
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		T::describe(w)
+	}
+
+	fn looks_through_invisible_groups() -> bool {
+		false
 	}
 }
 
+impl<T: Default> Default for Captured<T> {
+	fn default() -> Self {
+		Self {
+			delim_span: None,
+			inner: T::default(),
+		}
+	}
+}
+
+#[allow(missing_docs)]
 pub mod delimiters {
 	use super::{Delimited, BRACE, BRACKET, PARENTHESIS};
 
@@ -520,3 +925,63 @@ pub mod delimiters {
 	pub type Braces<'a, Content> = Delimited<'a, BRACE, Content>;
 	pub type Brackets<'a, Content> = Delimited<'a, BRACKET, Content>;
 }
+
+#[cfg(test)]
+mod tests {
+	use std::ptr;
+
+	use crate::{io::PARSE_RUNS, parse_str};
+
+	use super::{
+		delimiters::{Braces, Brackets},
+		punctuation::Semi,
+	};
+
+	fn parse_runs() -> usize {
+		PARSE_RUNS.with(std::cell::Cell::get)
+	}
+
+	#[test]
+	fn contents_are_parsed_on_first_access() {
+		parse_str("{ ; }", |parsing| {
+			let (braces, diagnostics) = parsing.parse::<Braces<Semi>>();
+			assert!(diagnostics.is_empty());
+
+			let before = parse_runs();
+			let contents = braces.contents();
+			assert!(parse_runs() > before);
+
+			let after = parse_runs();
+			assert!(ptr::eq(contents, braces.contents()));
+			assert!(braces.diagnostics().is_empty());
+			assert_eq!(parse_runs(), after);
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn groups_keep_their_own_diagnostics() {
+		parse_str("{ [,] ; }", |parsing| {
+			let (braces, diagnostics) = parsing.parse::<Braces<(Brackets<Semi>, Semi)>>();
+			assert!(diagnostics.is_empty());
+
+			let (brackets, _) = braces.contents();
+			assert!(braces.diagnostics().is_empty());
+			assert_eq!(
+				brackets.diagnostics().messages(),
+				[
+					"expected `;`, found `,`",
+					"expected end of input, found `,`"
+				]
+			);
+		})
+		.unwrap();
+	}
+
+	#[test]
+	fn defaulted_groups_are_empty() {
+		let braces = Braces::<Semi>::default();
+		assert!(braces.enclosed().is_empty());
+		assert!(braces.diagnostics().is_empty());
+	}
+}
@@ -1,16 +1,41 @@
 #![allow(deprecated)]
 
+use crate::io::edition::Edition;
+
 use super::{Keyword, KeywordString};
 
 macro_rules! keywords {
-	($($type_name:ident $enum_name:ident $keyword:literal),*$(,)?) => {$(
-		#[deprecated = "Please don't use this directly."]
-		pub enum $enum_name {}
-		impl KeywordString for $enum_name {
-			const KW: &'static str = $keyword;
-		}
-		pub type $type_name = Keyword<$enum_name>;
-	)*};
+	(@since) => { Edition::Edition2015 };
+	(@since $edition:ident) => { Edition::$edition };
+	(@weak) => { false };
+	(@weak $edition:ident) => { true };
+	($(
+		$type_name:ident $enum_name:ident $keyword:literal
+		$(since $edition:ident)?
+		$(weak before $weak_edition:ident)?
+	),*$(,)?) => {
+		$(
+			#[deprecated = "Please don't use this directly."]
+			pub enum $enum_name {}
+			impl KeywordString for $enum_name {
+				const KW: &'static str = $keyword;
+				const SINCE: Edition = keywords!(@since $($edition)? $($weak_edition)?);
+				const WEAK_BEFORE: bool = keywords!(@weak $($weak_edition)?);
+			}
+			pub type $type_name = Keyword<$enum_name>;
+		)*
+
+		/// All strict and reserved keywords with the edition they became keywords in.
+		pub const ALL: &[(&str, Edition)] =
+			&[$(($keyword, keywords!(@since $($edition)? $($weak_edition)?))),*];
+	};
+}
+
+/// Whether `word` is a strict or reserved keyword in `edition`, which makes it an identifier only if raw.
+#[must_use]
+pub fn is_keyword(word: &str, edition: Edition) -> bool {
+	ALL.iter()
+		.any(|&(keyword, since)| keyword == word && since <= edition)
 }
 
 keywords! {
@@ -52,9 +77,9 @@ keywords! {
 	While WHILE "while",
 
 	// strict (2018+):
-	Async ASYNC "async",
-	Await AWAIT "await",
-	Dyn DYN "dyn",
+	Async ASYNC "async" since Edition2018,
+	Await AWAIT "await" since Edition2018,
+	Dyn DYN "dyn" weak before Edition2018,
 
 	// reserved:
 	Abstract ABSTRACT "abstract",
@@ -71,5 +96,41 @@ keywords! {
 	Yield YIELD "yield",
 
 	// reserved (2018+):
-	Try TRY "try",
+	Try TRY "try" since Edition2018,
+
+	// reserved (2024+):
+	Gen GEN "gen" since Edition2024,
+}
+
+#[cfg(test)]
+mod tests {
+	use crate::io::edition::Edition;
+
+	use super::is_keyword;
+
+	#[test]
+	fn strict_keywords_are_keywords_in_every_edition() {
+		for edition in [
+			Edition::Edition2015,
+			Edition::Edition2018,
+			Edition::Edition2021,
+			Edition::Edition2024,
+		] {
+			assert!(is_keyword("fn", edition), "{edition}");
+			assert!(is_keyword("yield", edition), "{edition}");
+			assert!(!is_keyword("union", edition), "{edition}");
+		}
+	}
+
+	#[test]
+	fn edition_gated_keywords() {
+		for keyword in ["async", "await", "dyn", "try"] {
+			assert!(!is_keyword(keyword, Edition::Edition2015), "`{keyword}`");
+			assert!(is_keyword(keyword, Edition::Edition2018), "`{keyword}`");
+			assert!(is_keyword(keyword, Edition::Edition2024), "`{keyword}`");
+		}
+
+		assert!(!is_keyword("gen", Edition::Edition2021));
+		assert!(is_keyword("gen", Edition::Edition2024));
+	}
 }
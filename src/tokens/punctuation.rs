@@ -1,4 +1,8 @@
-use super::{Punctuation, SPunct};
+#![allow(deprecated)]
+
+use crate::io::edition::Edition;
+
+use super::{Keyword, KeywordString, Punctuation, SPunct};
 
 pub type Plus = Punctuation<(SPunct<'+', false>,)>;
 pub type Minus = Punctuation<(SPunct<'-', false>,)>;
@@ -31,7 +35,8 @@ pub type Lt = Punctuation<(SPunct<'<', false>,)>;
 pub type Ge = Punctuation<(SPunct<'>', true>, SPunct<'=', false>)>;
 pub type Le = Punctuation<(SPunct<'<', true>, SPunct<'=', false>)>;
 pub type At = Punctuation<(SPunct<'@', false>,)>;
-pub type Underscore = Punctuation<(SPunct<'_', false>,)>;
+/// `_` is an [`Ident`](`proc_macro2::Ident`) to `proc_macro2`, so it's parsed like a keyword.
+pub type Underscore = Keyword<UNDERSCORE>;
 pub type Dot = Punctuation<(SPunct<'.', false>,)>;
 pub type DotDot = Punctuation<(SPunct<'.', true>, SPunct<'.', false>)>;
 pub type DotDotDot = Punctuation<(SPunct<'.', true>, SPunct<'.', true>, SPunct<'.', false>)>;
@@ -47,3 +52,10 @@ pub type Pound = Punctuation<(SPunct<'#', false>,)>;
 pub type Dollar = Punctuation<(SPunct<'$', false>,)>;
 pub type Question = Punctuation<(SPunct<'?', false>,)>;
 pub type Tilde = Punctuation<(SPunct<'~', false>,)>;
+
+#[deprecated = "Please don't use this directly."]
+pub enum UNDERSCORE {}
+impl KeywordString for UNDERSCORE {
+	const KW: &'static str = "_";
+	const SINCE: Edition = Edition::Edition2015;
+}
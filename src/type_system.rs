@@ -1,2 +1,4 @@
+//! [The type system](https://doc.rust-lang.org/stable/reference/type-system.html).
+
 pub mod trait_and_lifetime_bounds;
 pub mod types;
@@ -1,8 +1,179 @@
+//! [Trait and lifetime bounds](https://doc.rust-lang.org/stable/reference/trait-bounds.html).
+
+use std::fmt::{self, Write};
+
 use crate::{
-	io::Parse,
-	tokens::{keywords::Static, punctuation::Underscore, LifetimeOrLabel, SPunct},
+	io::{first::First, Input, Parse},
+	names::paths::TypePath,
+	tokens::{
+		delimiters::Parentheses,
+		keywords::{For, Static},
+		punctuation::{Comma, Gt, Lt, Plus, Question, Underscore},
+		LifetimeOrLabel, SPunct,
+	},
 };
 
+/// Bounds separated by `+`, with an optional trailing `+`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct TypeParamBounds<'a> {
+	pub type_param_bound: TypeParamBound<'a>,
+	pub rest: Vec<(Plus, TypeParamBound<'a>)>,
+	pub plus: Option<Plus>,
+}
+
+impl<'a> Parse<'a> for TypeParamBounds<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			type_param_bound: input.parse(),
+			rest: input.parse(),
+			plus: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(TypeParamBound, Vec<(Plus, TypeParamBound)>, Option<Plus>)>::describe(w)
+	}
+
+	fn first() -> First {
+		TypeParamBound::first()
+	}
+}
+
+/// A lifetime or trait bound.
+#[derive(Debug, Clone)]
+#[allow(missing_docs, clippy::large_enum_variant)]
+pub enum TypeParamBound<'a> {
+	Lifetime(Lifetime),
+	TraitBound(TraitBound<'a>),
+}
+
+impl<'a> Parse<'a> for TypeParamBound<'a> {
+	#[allow(clippy::same_functions_in_if_condition)]
+	fn parse(input: &mut Input<'a>) -> Self {
+		if let Some(lifetime) = input.parse() {
+			Self::Lifetime(lifetime)
+		} else if let Some(trait_bound) = input.parse() {
+			Self::TraitBound(trait_bound)
+		} else {
+			input.error_expected()
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		w.write_char('(')?;
+		Lifetime::describe(w)?;
+		w.write_char('|')?;
+		TraitBound::describe(w)?;
+		w.write_char(')')
+	}
+
+	fn first() -> First {
+		Lifetime::first().or(TraitBound::first())
+	}
+}
+
+impl Default for TypeParamBound<'_> {
+	fn default() -> Self {
+		Self::TraitBound(TraitBound::default())
+	}
+}
+
+/// A path to a trait, optionally after `?` or `for<…>` and in parentheses.
+#[derive(Debug, Clone)]
+#[allow(missing_docs, clippy::large_enum_variant)]
+pub enum TraitBound<'a> {
+	Bare {
+		question: Option<Question>,
+		for_lifetimes: Option<ForLifetimes>,
+		type_path: TypePath<'a>,
+	},
+	Parenthesized(Parentheses<'a, (Option<Question>, Option<ForLifetimes>, TypePath<'a>)>),
+}
+
+impl<'a> Parse<'a> for TraitBound<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		if let Some(parentheses) = input.parse() {
+			Self::Parenthesized(parentheses)
+		} else {
+			Self::Bare {
+				question: input.parse(),
+				for_lifetimes: input.parse(),
+				type_path: input.parse(),
+			}
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Option<Question>, Option<ForLifetimes>, TypePath)>::describe(w)
+	}
+
+	fn first() -> First {
+		Parentheses::<(Option<Question>, Option<ForLifetimes>, TypePath)>::first()
+			.or(Question::first())
+			.or(ForLifetimes::first())
+			.or(TypePath::first())
+	}
+}
+
+impl Default for TraitBound<'_> {
+	fn default() -> Self {
+		Self::Bare {
+			question: None,
+			for_lifetimes: None,
+			type_path: TypePath::default(),
+		}
+	}
+}
+
+/// `for<'a, 'b>`.
+///
+/// Only plain lifetime parameters are accepted, without bounds or attributes.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct ForLifetimes {
+	pub r#for: For,
+	pub lt: Lt,
+	#[allow(clippy::type_complexity)]
+	pub lifetimes: Option<(
+		LifetimeOrLabel,
+		Vec<(Comma, LifetimeOrLabel)>,
+		Option<Comma>,
+	)>,
+	pub gt: Gt,
+}
+
+impl Parse<'_> for ForLifetimes {
+	fn parse(input: &mut Input<'_>) -> Self {
+		Self {
+			r#for: input.parse(),
+			lt: input.parse(),
+			lifetimes: input.parse(),
+			gt: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(
+			For,
+			Lt,
+			Option<(
+				LifetimeOrLabel,
+				Vec<(Comma, LifetimeOrLabel)>,
+				Option<Comma>,
+			)>,
+			Gt,
+		)>::describe(w)
+	}
+
+	fn first() -> First {
+		For::first()
+	}
+}
+
+/// `'a`, `'static` or `'_`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub enum Lifetime {
 	LifetimeOrLabel(LifetimeOrLabel),
 	Static(SPunct<'\'', true>, Static),
@@ -10,23 +181,29 @@ pub enum Lifetime {
 }
 
 impl Parse<'_> for Lifetime {
-	fn parse(input: &mut crate::io::Input<'_>) -> Self {
-		if let (lol, Ok(())) = input.try_parse() {
-			Self::LifetimeOrLabel(lol)
-		} else if let ((s_punct, r#static), Ok(())) = input.try_parse() {
+	#[allow(clippy::same_functions_in_if_condition)]
+	fn parse(input: &mut Input<'_>) -> Self {
+		// `'static` and `'_` come first, since `LifetimeOrLabel` would take them with an error.
+		if let ((s_punct, r#static), Ok(())) = input.try_parse() {
 			Self::Static(s_punct, r#static)
 		} else if let ((s_punct, underscore), Ok(())) = input.try_parse() {
 			Self::Transient(s_punct, underscore)
+		} else if let (lol, Ok(())) = input.try_parse() {
+			Self::LifetimeOrLabel(lol)
 		} else {
 			input.error_expected()
 		}
 	}
 
-	fn describe(w: &mut dyn std::fmt::Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		w.write_char('(')?;
 		LifetimeOrLabel::describe(w)?;
 		w.write_str("|`'static`|`'_`)")
 	}
+
+	fn first() -> First {
+		LifetimeOrLabel::first()
+	}
 }
 
 impl Default for Lifetime {
@@ -1,16 +1,40 @@
-use std::fmt::Write;
+//! [Types](https://doc.rust-lang.org/stable/reference/types.html).
+
+use std::fmt::{self, Write};
+
+use either::Either;
+use proc_macro2::Literal;
 
 use crate::{
-	io::{Input, Parse},
+	attributes::OuterAttribute,
+	expressions::Expression,
+	help::{catalog::Message, codes::Code, Applicability, Diagnostic, Edit, Suggestion},
+	identifiers::Identifier,
+	io::{
+		arena::Node,
+		first::First,
+		memo::Memoization,
+		random_access::{self, TokenTree},
+		Input, Parse,
+	},
 	macros::MacroInvocation,
-	names::paths::TypePath,
-	tokens::{delimiters::Parentheses, punctuation::Comma},
+	names::paths::{QualifiedPathInType, TypePath},
+	tokens::{
+		delimiters::{Brackets, Parentheses},
+		keywords::{Const, Dyn, Extern, Fn, Impl, Mut, Unsafe},
+		punctuation::{And, Colon, Comma, Plus, RArrow, Semi, Star, Underscore},
+	},
 };
 
 use self::never_type::NeverType;
 
+use super::trait_and_lifetime_bounds::{ForLifetimes, Lifetime, TraitBound, TypeParamBounds};
+
 pub mod never_type;
 
+/// Any type, including `impl` and `dyn` types with several bounds.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub enum Type<'a> {
 	TypeNoBounds(TypeNoBounds<'a>),
 	ImplTraitType(ImplTraitType<'a>),
@@ -18,10 +42,23 @@ pub enum Type<'a> {
 }
 
 impl<'a> Parse<'a> for Type<'a> {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut Input<'a>) -> Self {
-		if let (type_no_bounds, Ok(())) = input.try_parse() {
-			Self::TypeNoBounds(type_no_bounds)
-		} else if let (impl_trait_type, Ok(())) = input.try_parse() {
+		// Without this, alternatives that continue past a missing first token would be attempted in vain.
+		if input.is_end() {
+			return input.error_expected();
+		}
+
+		// A type without bounds is only one if no further bound follows it.
+		let mut no_bounds = input.clone();
+		if let (type_no_bounds, Ok(())) = no_bounds.try_parse() {
+			if !no_bounds.peek::<Plus>() {
+				*input = no_bounds;
+				return Self::TypeNoBounds(type_no_bounds);
+			}
+		}
+
+		if let (impl_trait_type, Ok(())) = input.try_parse() {
 			Self::ImplTraitType(impl_trait_type)
 		} else if let (trait_object_type, Ok(())) = input.try_parse() {
 			Self::TraitObjectType(trait_object_type)
@@ -30,11 +67,31 @@ impl<'a> Parse<'a> for Type<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
-		w.write_str("Type")
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-type").write(w)
+	}
+
+	fn memoization() -> Option<Memoization<Self>> {
+		// SAFETY: The marker is `Self` with all lifetimes `'static`, and dropping `Self` doesn't use what it borrows.
+		Some(unsafe { Memoization::new::<Type<'static>>() })
+	}
+
+	fn first() -> First {
+		TypeNoBounds::first()
+			.or(ImplTraitType::first())
+			.or(TraitObjectType::first())
 	}
 }
 
+impl Default for Type<'_> {
+	fn default() -> Self {
+		Self::TypeNoBounds(TypeNoBounds::default())
+	}
+}
+
+/// A type that can't be followed by `+` bounds.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
 pub enum TypeNoBounds<'a> {
 	ParenthesizedOrTupleType(ParenthesizedOrTupleType<'a>),
 	ImplTraitTypeOneBound(ImplTraitTypeOneBound<'a>),
@@ -45,47 +102,66 @@ pub enum TypeNoBounds<'a> {
 	ReferenceType(ReferenceType<'a>),
 	ArrayType(ArrayType<'a>),
 	SliceType(SliceType<'a>),
-	InferredType(InferredType<'a>),
+	InferredType(InferredType),
 	QualifiedPathInType(QualifiedPathInType<'a>),
 	BareFunctionType(BareFunctionType<'a>),
 	MacroInvocation(MacroInvocation<'a>),
 }
 
 impl<'a> Parse<'a> for TypeNoBounds<'a> {
+	#[allow(clippy::same_functions_in_if_condition)]
 	fn parse(input: &mut Input<'a>) -> Self {
-		if let (ParenthesizedOrTupleType, Ok(())) = input.try_parse() {
-			Self::ParenthesizedOrTupleType(ParenthesizedOrTupleType)
-		} else if let (ImplTraitTypeOneBound, Ok(())) = input.try_parse() {
-			Self::ImplTraitTypeOneBound(ImplTraitTypeOneBound)
-		} else if let (TraitObjectTypeOneBound, Ok(())) = input.try_parse() {
-			Self::TraitObjectTypeOneBound(TraitObjectTypeOneBound)
-		} else if let (TypePath, Ok(())) = input.try_parse() {
-			Self::TypePath(TypePath)
-		} else if let (NeverType, Ok(())) = input.try_parse() {
-			Self::NeverType(NeverType)
-		} else if let (RawPointerType, Ok(())) = input.try_parse() {
-			Self::RawPointerType(RawPointerType)
-		} else if let (ReferenceType, Ok(())) = input.try_parse() {
-			Self::ReferenceType(ReferenceType)
-		} else if let (ArrayType, Ok(())) = input.try_parse() {
-			Self::ArrayType(ArrayType)
-		} else if let (SliceType, Ok(())) = input.try_parse() {
-			Self::SliceType(SliceType)
-		} else if let (InferredType, Ok(())) = input.try_parse() {
-			Self::InferredType(InferredType)
-		} else if let (QualifiedPathInType, Ok(())) = input.try_parse() {
-			Self::QualifiedPathInType(QualifiedPathInType)
-		} else if let (BareFunctionType, Ok(())) = input.try_parse() {
-			Self::BareFunctionType(BareFunctionType)
-		} else if let (MacroInvocation, Ok(())) = input.try_parse() {
-			Self::MacroInvocation(MacroInvocation)
+		// Each alternative is only attempted if its FIRST set admits the next token.
+		// Bare trait objects that are only a path are parsed as `TypePath`, like by rustc.
+		if let Some(parenthesized_or_tuple_type) = input.parse() {
+			Self::ParenthesizedOrTupleType(parenthesized_or_tuple_type)
+		} else if let Some(impl_trait_type_one_bound) = input.parse() {
+			Self::ImplTraitTypeOneBound(impl_trait_type_one_bound)
+		} else if let Some(bare_function_type) = input.parse() {
+			Self::BareFunctionType(bare_function_type)
+		} else if let Some(macro_invocation) = input.parse() {
+			Self::MacroInvocation(macro_invocation)
+		} else if let Some(type_path) = input.parse() {
+			Self::TypePath(type_path)
+		} else if let Some(trait_object_type_one_bound) = input.parse() {
+			Self::TraitObjectTypeOneBound(trait_object_type_one_bound)
+		} else if let Some(never_type) = input.parse() {
+			Self::NeverType(never_type)
+		} else if let Some(raw_pointer_type) = input.parse() {
+			Self::RawPointerType(raw_pointer_type)
+		} else if let Some(reference_type) = input.parse() {
+			Self::ReferenceType(reference_type)
+		} else if let Some(array_type) = input.parse() {
+			Self::ArrayType(array_type)
+		} else if let Some(slice_type) = input.parse() {
+			Self::SliceType(slice_type)
+		} else if let Some(inferred_type) = input.parse() {
+			Self::InferredType(inferred_type)
+		} else if let Some(qualified_path_in_type) = input.parse() {
+			Self::QualifiedPathInType(qualified_path_in_type)
 		} else {
 			input.error_expected()
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
-		w.write_str("TypeNoBounds")
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Message::new("describe-type-no-bounds").write(w)
+	}
+
+	fn first() -> First {
+		ParenthesizedOrTupleType::first()
+			.or(ImplTraitTypeOneBound::first())
+			.or(BareFunctionType::first())
+			.or(MacroInvocation::first())
+			.or(TypePath::first())
+			.or(TraitObjectTypeOneBound::first())
+			.or(NeverType::first())
+			.or(RawPointerType::first())
+			.or(ReferenceType::first())
+			.or(ArrayType::first())
+			.or(SliceType::first())
+			.or(InferredType::first())
+			.or(QualifiedPathInType::first())
 	}
 }
 
@@ -95,8 +171,13 @@ impl Default for TypeNoBounds<'_> {
 	}
 }
 
+/// `( Type )` or a tuple type like `()`, `(T,)` or `(T, U)`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct ParenthesizedOrTupleType<'a> {
-	parens: Parentheses<'a, (Type<'a>, Vec<(Comma, Type<'a>)>, Option<Comma>)>,
+	#[allow(clippy::type_complexity)]
+	pub parens:
+		Parentheses<'a, Option<(Node<'a, Type<'a>>, Vec<(Comma, Type<'a>)>, Option<Comma>)>>,
 }
 
 impl<'a> Parse<'a> for ParenthesizedOrTupleType<'a> {
@@ -106,7 +187,382 @@ impl<'a> Parse<'a> for ParenthesizedOrTupleType<'a> {
 		}
 	}
 
-	fn describe(w: &mut dyn Write) {
-		Parentheses::<(Type, Vec<(Comma, Type)>, Option<Comma>)>::describe(w)
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Parentheses::<Option<(Type, Vec<(Comma, Type)>, Option<Comma>)>>::describe(w)
+	}
+
+	fn first() -> First {
+		Parentheses::<Option<(Type, Vec<(Comma, Type)>, Option<Comma>)>>::first()
+	}
+}
+
+/// `impl Bound + …`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct ImplTraitType<'a> {
+	pub r#impl: Impl,
+	pub type_param_bounds: TypeParamBounds<'a>,
+}
+
+impl<'a> Parse<'a> for ImplTraitType<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			r#impl: input.parse(),
+			type_param_bounds: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Impl, TypeParamBounds)>::describe(w)
+	}
+
+	fn first() -> First {
+		Impl::first()
+	}
+}
+
+/// `impl Bound` with a single trait bound.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct ImplTraitTypeOneBound<'a> {
+	pub r#impl: Impl,
+	pub trait_bound: TraitBound<'a>,
+}
+
+impl<'a> Parse<'a> for ImplTraitTypeOneBound<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			r#impl: input.parse(),
+			trait_bound: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Impl, TraitBound)>::describe(w)
+	}
+
+	fn first() -> First {
+		Impl::first()
+	}
+}
+
+/// `dyn Bound + …`, where editions before 2021 also accept it without `dyn`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct TraitObjectType<'a> {
+	pub r#dyn: Option<Dyn>,
+	pub type_param_bounds: TypeParamBounds<'a>,
+}
+
+impl<'a> Parse<'a> for TraitObjectType<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		let r#dyn: Option<Dyn> = input.parse();
+		let start = input.cursor;
+		let type_param_bounds = input.parse();
+		if r#dyn.is_none() {
+			report_bare_trait_object(input, start);
+		}
+		Self {
+			r#dyn,
+			type_param_bounds,
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Option<Dyn>, TypeParamBounds)>::describe(w)
+	}
+
+	fn first() -> First {
+		Dyn::first().or(TypeParamBounds::first())
+	}
+}
+
+/// `dyn Bound` with a single trait bound.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct TraitObjectTypeOneBound<'a> {
+	pub r#dyn: Option<Dyn>,
+	pub trait_bound: TraitBound<'a>,
+}
+
+impl<'a> Parse<'a> for TraitObjectTypeOneBound<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		let r#dyn: Option<Dyn> = input.parse();
+		let start = input.cursor;
+		let trait_bound = input.parse();
+		if r#dyn.is_none() {
+			report_bare_trait_object(input, start);
+		}
+		Self { r#dyn, trait_bound }
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Option<Dyn>, TraitBound)>::describe(w)
+	}
+
+	fn first() -> First {
+		Dyn::first().or(TraitBound::first())
+	}
+}
+
+/// Reports the bounds parsed since `start` as trait object without `dyn`, unless `input`'s edition allows that.
+fn report_bare_trait_object<'a>(input: &Input<'a>, start: &'a [TokenTree]) {
+	let bounds = &start[..start.len() - input.cursor.len()];
+	if let Some(span) =
+		random_access::span(bounds).filter(|_| !input.edition.allows_bare_trait_objects())
+	{
+		input.diagnostics.push(
+			Diagnostic::error(
+				span,
+				Message::new("bare-trait-object").arg("edition", input.edition),
+			)
+			.with_code(Code::EDITION_GATED)
+			.with_suggestion(Suggestion {
+				message: Message::new("insert-dyn"),
+				edit: Edit::InsertBefore(span),
+				replacement: "dyn ".to_string(),
+				applicability: Applicability::MachineApplicable,
+			})
+			.located(input, bounds)
+			.recovered(),
+		);
+	}
+}
+
+/// `*const T` or `*mut T`.
+#[derive(Debug, Clone)]
+#[allow(missing_docs)]
+pub struct RawPointerType<'a> {
+	pub star: Star,
+	pub mut_or_const: Either<Mut, Const>,
+	pub type_no_bounds: Node<'a, TypeNoBounds<'a>>,
+}
+
+impl<'a> Parse<'a> for RawPointerType<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			star: input.parse(),
+			mut_or_const: input.parse(),
+			type_no_bounds: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(Star, Either<Mut, Const>, TypeNoBounds)>::describe(w)
+	}
+
+	fn first() -> First {
+		Star::first()
+	}
+}
+
+impl Default for RawPointerType<'_> {
+	fn default() -> Self {
+		Self {
+			star: Star::default(),
+			mut_or_const: Either::Right(Const::default()),
+			type_no_bounds: Node::default(),
+		}
+	}
+}
+
+/// `&T` or `&'a T`, optionally `mut`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct ReferenceType<'a> {
+	pub and: And,
+	pub lifetime: Option<Lifetime>,
+	pub r#mut: Option<Mut>,
+	pub type_no_bounds: Node<'a, TypeNoBounds<'a>>,
+}
+
+impl<'a> Parse<'a> for ReferenceType<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			and: input.parse(),
+			lifetime: input.parse(),
+			r#mut: input.parse(),
+			type_no_bounds: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(And, Option<Lifetime>, Option<Mut>, TypeNoBounds)>::describe(w)
+	}
+
+	fn first() -> First {
+		And::first()
+	}
+}
+
+/// `[T; N]`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct ArrayType<'a> {
+	pub brackets: Brackets<'a, (Node<'a, Type<'a>>, Semi, Node<'a, Expression<'a>>)>,
+}
+
+impl<'a> Parse<'a> for ArrayType<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		// Only the `;` tells this apart from a slice type, since the contents are parsed lazily.
+		if !random_access::group_contains_punct(input.cursor, ';') {
+			return input.error_expected();
+		}
+		Self {
+			brackets: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		w.write_str("`[ … ; … ]`")
+	}
+
+	fn first() -> First {
+		Brackets::<(Type, Semi, Expression)>::first()
+	}
+}
+
+/// `[T]`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct SliceType<'a> {
+	pub brackets: Brackets<'a, Node<'a, Type<'a>>>,
+}
+
+impl<'a> Parse<'a> for SliceType<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			brackets: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Brackets::<Type>::describe(w)
+	}
+
+	fn first() -> First {
+		Brackets::<Type>::first()
+	}
+}
+
+/// `_`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct InferredType {
+	pub underscore: Underscore,
+}
+
+impl Parse<'_> for InferredType {
+	fn parse(input: &mut Input<'_>) -> Self {
+		Self {
+			underscore: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		Underscore::describe(w)
+	}
+
+	fn first() -> First {
+		Underscore::first()
+	}
+}
+
+/// `fn(A, B) -> C`, optionally after `for<…>`, `unsafe` and `extern`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct BareFunctionType<'a> {
+	pub for_lifetimes: Option<ForLifetimes>,
+	pub r#unsafe: Option<Unsafe>,
+	/// `extern` with an optional ABI string.
+	pub r#extern: Option<(Extern, Option<Literal>)>,
+	pub r#fn: Fn,
+	pub parentheses: Parentheses<'a, Option<MaybeNamedFunctionParameters<'a>>>,
+	pub return_type: Option<(RArrow, Node<'a, TypeNoBounds<'a>>)>,
+}
+
+impl<'a> Parse<'a> for BareFunctionType<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			for_lifetimes: input.parse(),
+			r#unsafe: input.parse(),
+			r#extern: input.parse(),
+			r#fn: input.parse(),
+			parentheses: input.parse(),
+			return_type: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(
+			Option<ForLifetimes>,
+			Option<Unsafe>,
+			Option<(Extern, Option<Literal>)>,
+			Fn,
+			Parentheses<Option<MaybeNamedFunctionParameters>>,
+			Option<(RArrow, TypeNoBounds)>,
+		)>::describe(w)
+	}
+
+	fn first() -> First {
+		ForLifetimes::first()
+			.or(Unsafe::first())
+			.or(Extern::first())
+			.or(Fn::first())
+	}
+}
+
+//TODO: Variadic parameters.
+/// The comma-separated parameters of a [`BareFunctionType`].
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct MaybeNamedFunctionParameters<'a> {
+	pub maybe_named_param: MaybeNamedParam<'a>,
+	pub rest: Vec<(Comma, MaybeNamedParam<'a>)>,
+	pub comma: Option<Comma>,
+}
+
+impl<'a> Parse<'a> for MaybeNamedFunctionParameters<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			maybe_named_param: input.parse(),
+			rest: input.parse(),
+			comma: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(
+			MaybeNamedParam,
+			Vec<(Comma, MaybeNamedParam)>,
+			Option<Comma>,
+		)>::describe(w)
+	}
+}
+
+/// A function pointer parameter's type, optionally after a name.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
+pub struct MaybeNamedParam<'a> {
+	pub outer_attributes: Vec<OuterAttribute<'a>>,
+	pub name: Option<(Either<Identifier, Underscore>, Colon)>,
+	pub r#type: Node<'a, Type<'a>>,
+}
+
+impl<'a> Parse<'a> for MaybeNamedParam<'a> {
+	fn parse(input: &mut Input<'a>) -> Self {
+		Self {
+			outer_attributes: input.parse(),
+			name: input.parse(),
+			r#type: input.parse(),
+		}
+	}
+
+	fn describe(w: &mut dyn Write) -> fmt::Result {
+		<(
+			Vec<OuterAttribute>,
+			Option<(Either<Identifier, Underscore>, Colon)>,
+			Type,
+		)>::describe(w)
 	}
 }
@@ -1,12 +1,17 @@
-use std::fmt::Write;
+//! [The never type](https://doc.rust-lang.org/stable/reference/types/never.html).
+
+use std::fmt::{self, Write};
 
 use crate::{
-	io::{Input, Parse},
+	io::{first::First, Input, Parse},
 	tokens::punctuation::Not,
 };
 
+/// `!`.
+#[derive(Debug, Clone, Default)]
+#[allow(missing_docs)]
 pub struct NeverType {
-	not: Not,
+	pub not: Not,
 }
 
 impl Parse<'_> for NeverType {
@@ -14,15 +19,11 @@ impl Parse<'_> for NeverType {
 		Self { not: input.parse() }
 	}
 
-	fn describe(w: &mut dyn Write) {
+	fn describe(w: &mut dyn Write) -> fmt::Result {
 		Not::describe(w)
 	}
-}
 
-impl Default for NeverType {
-	fn default() -> Self {
-		Self {
-			not: Not::default(),
-		}
+	fn first() -> First {
+		Not::first()
 	}
 }